@@ -0,0 +1,538 @@
+use std::fmt::Write;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use aoc2024::days::DAYS;
+use aoc2024::input::read_input;
+use aoc2024::solution::Solver;
+use nom::branch::alt;
+use nom::bytes::complete::take_while;
+use nom::character::complete::{char, multispace0, u64 as json_number};
+use nom::combinator::{all_consuming, map};
+use nom::multi::separated_list0;
+use nom::sequence::{delimited, separated_pair};
+use nom::IResult;
+
+use crate::parse_day;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BenchOptions {
+    /// Empty to benchmark every day.
+    pub days: Vec<u8>,
+    /// Only allowed when benchmarking a single day.
+    pub input: Option<PathBuf>,
+    pub iterations: usize,
+    /// Untimed runs before the timed ones, to warm up caches and the thread pool.
+    pub warmup: usize,
+    /// How to write `output`, JSON unless given.
+    pub format: Option<Format>,
+    pub output: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    /// How many percent slower than its baseline median a stage may get before it fails.
+    pub threshold: u32,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            days: Vec::new(),
+            input: None,
+            iterations: 10,
+            warmup: 2,
+            format: None,
+            output: None,
+            baseline: None,
+            threshold: 10,
+        }
+    }
+}
+
+pub fn parse_options(mut args: impl Iterator<Item = String>) -> Result<BenchOptions, String> {
+    let mut options = BenchOptions::default();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            if arg != "all" {
+                options.days.push(parse_day(&arg)?);
+            }
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing a value after {arg}"))?;
+        let count = || {
+            value
+                .parse()
+                .map_err(|_| format!("{arg} needs a whole number, not {value:?}"))
+        };
+        match arg.as_str() {
+            "--iterations" => options.iterations = count()?,
+            "--warmup" => options.warmup = count()?,
+            "--threshold" => {
+                options.threshold = value
+                    .parse()
+                    .map_err(|_| format!("{arg} needs a whole percentage, not {value:?}"))?;
+            }
+            "--format" => {
+                options.format = Some(match value.as_str() {
+                    "json" => Format::Json,
+                    "csv" => Format::Csv,
+                    _ => return Err(format!("Unknown format {value:?}, use json or csv")),
+                });
+            }
+            "--input" => options.input = Some(PathBuf::from(value)),
+            "--output" => options.output = Some(PathBuf::from(value)),
+            "--baseline" => options.baseline = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown option {arg:?}")),
+        }
+    }
+    if options.iterations == 0 {
+        return Err("Benchmarks need at least one iteration".to_owned());
+    }
+    if options.input.is_some() && options.days.len() != 1 {
+        return Err("An input file can only be given when benchmarking a single day".to_owned());
+    }
+    if options.format.is_some() && options.output.is_none() {
+        return Err("A format can only be given along with --output".to_owned());
+    }
+    Ok(options)
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    const ALL: [Self; 3] = [Self::Parse, Self::Part1, Self::Part2];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part_1",
+            Self::Part2 => "part_2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|stage| stage.name() == name)
+    }
+}
+
+/// Summary statistics of one stage of one day, in nanoseconds.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Timing {
+    pub day: u8,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+}
+
+impl Timing {
+    fn from_samples(day: u8, stage: Stage, samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u64> = samples
+            .iter()
+            .map(|sample| u64::try_from(sample.as_nanos()).unwrap_or(u64::MAX))
+            .collect();
+        nanos.sort_unstable();
+        let middle = nanos.len() / 2;
+        let median = if nanos.len().is_multiple_of(2) {
+            nanos[middle - 1].midpoint(nanos[middle])
+        } else {
+            nanos[middle]
+        };
+        let total: u128 = nanos.iter().copied().map(u128::from).sum();
+        Self {
+            day,
+            stage,
+            iterations: nanos.len(),
+            min: nanos[0],
+            median,
+            mean: u64::try_from(total / nanos.len() as u128).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+/// Times parsing and each part separately, after the warm-up runs.
+fn bench_day(solver: &Solver, text: &str, options: &BenchOptions) -> Vec<Timing> {
+    for _ in 0..options.warmup {
+        let parsed = solver.parse(text);
+        black_box(parsed.part_1());
+        black_box(parsed.part_2());
+    }
+    let mut samples = Stage::ALL.map(|_| Vec::with_capacity(options.iterations));
+    for _ in 0..options.iterations {
+        let start = Instant::now();
+        let parsed = black_box(solver.parse(black_box(text)));
+        samples[0].push(start.elapsed());
+        let start = Instant::now();
+        black_box(parsed.part_1());
+        samples[1].push(start.elapsed());
+        let start = Instant::now();
+        black_box(parsed.part_2());
+        samples[2].push(start.elapsed());
+    }
+    Stage::ALL
+        .iter()
+        .zip(&samples)
+        .map(|(stage, samples)| Timing::from_samples(solver.day(), *stage, samples))
+        .collect()
+}
+
+fn to_json(timings: &[Timing]) -> String {
+    let rows: Vec<String> = timings
+        .iter()
+        .map(|timing| {
+            format!(
+                "  {{\"day\": {}, \"stage\": \"{}\", \"iterations\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}}}",
+                timing.day,
+                timing.stage.name(),
+                timing.iterations,
+                timing.min,
+                timing.median,
+                timing.mean
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+fn to_csv(timings: &[Timing]) -> String {
+    let mut csv = String::from("day,stage,iterations,min_ns,median_ns,mean_ns\n");
+    for timing in timings {
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{},{}",
+            timing.day,
+            timing.stage.name(),
+            timing.iterations,
+            timing.min,
+            timing.median,
+            timing.mean
+        );
+    }
+    csv
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum JsonValue<'a> {
+    Number(u64),
+    Text(&'a str),
+}
+
+fn json_string(input: &str) -> IResult<&str, &str> {
+    delimited(char('"'), take_while(|c| c != '"'), char('"'))(input)
+}
+
+fn json_field(input: &str) -> IResult<&str, (&str, JsonValue<'_>)> {
+    separated_pair(
+        json_string,
+        delimited(multispace0, char(':'), multispace0),
+        alt((
+            map(json_number, JsonValue::Number),
+            map(json_string, JsonValue::Text),
+        )),
+    )(input)
+}
+
+fn json_list<'a, T>(
+    open: char,
+    item: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    close: char,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    delimited(
+        delimited(multispace0, char(open), multispace0),
+        separated_list0(delimited(multispace0, char(','), multispace0), item),
+        delimited(multispace0, char(close), multispace0),
+    )
+}
+
+/// Reads back the flat list of objects written by [`to_json`].
+fn timings_from_json(text: &str) -> Result<Vec<Timing>, String> {
+    let (_rest, objects) =
+        all_consuming(json_list('[', json_list('{', json_field, '}'), ']'))(text)
+            .map_err(|err| format!("Not a list of timings: {err}"))?;
+    objects
+        .iter()
+        .map(|fields| {
+            let number = |key: &str| {
+                fields
+                    .iter()
+                    .find_map(|(name, value)| match value {
+                        JsonValue::Number(number) if *name == key => Some(*number),
+                        _ => None,
+                    })
+                    .ok_or_else(|| format!("A timing has no number {key:?}"))
+            };
+            let stage = fields
+                .iter()
+                .find_map(|(name, value)| match value {
+                    JsonValue::Text(text) if *name == "stage" => Stage::from_name(text),
+                    _ => None,
+                })
+                .ok_or("A timing has no known stage")?;
+            Ok(Timing {
+                day: u8::try_from(number("day")?).map_err(|err| err.to_string())?,
+                stage,
+                iterations: usize::try_from(number("iterations")?)
+                    .map_err(|err| err.to_string())?,
+                min: number("min_ns")?,
+                median: number("median_ns")?,
+                mean: number("mean_ns")?,
+            })
+        })
+        .collect()
+}
+
+/// Reads back the rows written by [`to_csv`].
+fn timings_from_csv(text: &str) -> Result<Vec<Timing>, String> {
+    text.lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let bad_row = || format!("Not a timing row: {line:?}");
+            let [day, stage, iterations, min, median, mean] = line
+                .split(',')
+                .map(str::trim)
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| bad_row())?;
+            Ok(Timing {
+                day: day.parse().map_err(|_| bad_row())?,
+                stage: Stage::from_name(stage).ok_or_else(bad_row)?,
+                iterations: iterations.parse().map_err(|_| bad_row())?,
+                min: min.parse().map_err(|_| bad_row())?,
+                median: median.parse().map_err(|_| bad_row())?,
+                mean: mean.parse().map_err(|_| bad_row())?,
+            })
+        })
+        .collect()
+}
+
+fn read_baseline(text: &str) -> Result<Vec<Timing>, String> {
+    if text.trim_start().starts_with('[') {
+        timings_from_json(text)
+    } else {
+        timings_from_csv(text)
+    }
+}
+
+/// Returns `true` if `current` is more than `threshold` percent slower than `baseline`.
+fn regressed(current: u64, baseline: u64, threshold: u32) -> bool {
+    u128::from(current) * 100 > u128::from(baseline) * (100 + u128::from(threshold))
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn percent_change(current: u64, baseline: u64) -> f64 {
+    (current as f64 / baseline.max(1) as f64 - 1.0) * 100.0
+}
+
+/// How one stage's median compares with its baseline.
+#[derive(Debug, PartialEq, Eq)]
+struct Comparison {
+    day: u8,
+    stage: Stage,
+    current: u64,
+    baseline: u64,
+    regressed: bool,
+}
+
+impl Comparison {
+    fn label(&self) -> String {
+        format!("Day {:02} {}", self.day, self.stage.name())
+    }
+
+    fn change(&self) -> f64 {
+        percent_change(self.current, self.baseline)
+    }
+}
+
+/// Compares medians against the baseline, for the stages it has timings of.
+fn compare(current: &[Timing], baseline: &[Timing], threshold: u32) -> Vec<Comparison> {
+    current
+        .iter()
+        .filter_map(|timing| {
+            let before = baseline
+                .iter()
+                .find(|before| before.day == timing.day && before.stage == timing.stage)?;
+            Some(Comparison {
+                day: timing.day,
+                stage: timing.stage,
+                current: timing.median,
+                baseline: before.median,
+                regressed: regressed(timing.median, before.median, threshold),
+            })
+        })
+        .collect()
+}
+
+pub fn run(options: &BenchOptions) -> Result<(), String> {
+    let baseline = options
+        .baseline
+        .as_ref()
+        .map(|path| {
+            std::fs::read_to_string(path)
+                .map_err(|err| format!("Couldn't read baseline {}: {err}", path.display()))
+                .and_then(|text| read_baseline(&text))
+        })
+        .transpose()?;
+    let mut timings = Vec::new();
+    for solver in DAYS
+        .iter()
+        .filter(|solver| options.days.is_empty() || options.days.contains(&solver.day()))
+    {
+        let text =
+            read_input(solver.day(), options.input.as_deref()).map_err(|err| err.to_string())?;
+        for timing in bench_day(solver, &text, options) {
+            println!(
+                "Day {:02} {:<7} min {:>12?}  median {:>12?}  mean {:>12?}",
+                timing.day,
+                timing.stage.name(),
+                Duration::from_nanos(timing.min),
+                Duration::from_nanos(timing.median),
+                Duration::from_nanos(timing.mean),
+            );
+            timings.push(timing);
+        }
+    }
+    if let Some(path) = &options.output {
+        let contents = match options.format.unwrap_or(Format::Json) {
+            Format::Json => to_json(&timings),
+            Format::Csv => to_csv(&timings),
+        };
+        std::fs::write(path, contents)
+            .map_err(|err| format!("Couldn't write {}: {err}", path.display()))?;
+    }
+    if let Some(baseline) = baseline {
+        let mut regressions = Vec::new();
+        for comparison in compare(&timings, &baseline, options.threshold) {
+            println!(
+                "{:<16} {:>12?} vs {:>12?} {:+7.1}%{}",
+                comparison.label(),
+                Duration::from_nanos(comparison.current),
+                Duration::from_nanos(comparison.baseline),
+                comparison.change(),
+                if comparison.regressed {
+                    "  REGRESSED"
+                } else {
+                    ""
+                }
+            );
+            if comparison.regressed {
+                regressions.push(format!(
+                    "{} is {:.1}% slower",
+                    comparison.label(),
+                    comparison.change()
+                ));
+            }
+        }
+        if !regressions.is_empty() {
+            return Err(format!(
+                "Slower than the baseline by more than {}%:\n    {}",
+                options.threshold,
+                regressions.join("\n    ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn timings() -> Vec<Timing> {
+        vec![
+            Timing::from_samples(6, Stage::Part2, &[4, 1, 3, 2].map(Duration::from_nanos)),
+            Timing::from_samples(9, Stage::Parse, &[Duration::from_nanos(7)]),
+        ]
+    }
+
+    #[test]
+    fn bench_test_parse_options() {
+        let parse = |args: &str| parse_options(args.split_whitespace().map(str::to_owned));
+        assert_eq!(parse(""), Ok(BenchOptions::default()));
+        assert_eq!(
+            parse("6 9 --iterations 3 --format csv --output out.csv --baseline base.csv --threshold 25"),
+            Ok(BenchOptions {
+                days: vec![6, 9],
+                iterations: 3,
+                format: Some(Format::Csv),
+                output: Some(PathBuf::from("out.csv")),
+                baseline: Some(PathBuf::from("base.csv")),
+                threshold: 25,
+                ..BenchOptions::default()
+            })
+        );
+        assert!(parse("--iterations 0").is_err());
+        assert_eq!(
+            parse("6 --input -").map(|options| options.input),
+            Ok(Some(PathBuf::from("-")))
+        );
+        assert!(parse("--format xml --output out.xml").is_err());
+        assert!(parse("--format csv").is_err());
+        assert!(parse("6 9 --input example.txt").is_err());
+        assert!(parse("--input example.txt").is_err());
+        assert!(parse("99").is_err());
+    }
+
+    #[test]
+    fn bench_test_statistics() {
+        let timings = timings();
+        assert_eq!(
+            (timings[0].min, timings[0].median, timings[0].mean),
+            (1, 2, 2)
+        );
+        assert_eq!(timings[1].median, 7);
+    }
+
+    #[test]
+    fn bench_test_baseline_round_trip() {
+        let timings = timings();
+        assert_eq!(read_baseline(&to_json(&timings)), Ok(timings.clone()));
+        assert_eq!(read_baseline(&to_csv(&timings)), Ok(timings.clone()));
+        assert_eq!(read_baseline("[]"), Ok(Vec::new()));
+        assert!(read_baseline("[{\"day\": 6}]").is_err());
+    }
+
+    #[test]
+    fn bench_test_regressions() {
+        assert!(!regressed(110, 100, 10));
+        assert!(regressed(111, 100, 10));
+        let baseline = timings();
+        let mut current = timings();
+        current[0].median *= 2;
+        let comparisons = compare(&current, &baseline, 10);
+        assert_eq!(
+            comparisons,
+            [
+                Comparison {
+                    day: 6,
+                    stage: Stage::Part2,
+                    current: 4,
+                    baseline: 2,
+                    regressed: true,
+                },
+                Comparison {
+                    day: 9,
+                    stage: Stage::Parse,
+                    current: 7,
+                    baseline: 7,
+                    regressed: false,
+                },
+            ]
+        );
+        assert_eq!(comparisons[0].label(), "Day 06 part_2");
+        assert!(compare(&current, &[], 10).is_empty());
+    }
+}
@@ -0,0 +1,184 @@
+mod bench;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc2024::days::DAYS;
+use aoc2024::input::read_input;
+use aoc2024::solution::Solver;
+
+const USAGE: &str = "\
+Usage:
+    aoc run <day|all> [--part <1|2>] [--input <path|->]
+    aoc list
+    aoc bench [<day>...] [--input <path|->] [--iterations <n>] [--warmup <n>]
+              [--output <path> [--format <json|csv>]] [--baseline <path>]
+              [--threshold <percent>]
+
+Inputs are read from --input, where - is standard input, or else from
+$AOC_INPUT_DIR/NN.txt or the crate's data/NN.txt.
+
+bench times parsing and each part separately, writing the timings to --output
+as JSON, or as CSV with --format csv. --input needs a single day. With
+--baseline it fails if a median got more than --threshold percent slower than
+the baseline's, 10% by default.";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    List,
+    Run {
+        /// `None` runs every day.
+        day: Option<u8>,
+        /// `None` runs both parts.
+        part: Option<u8>,
+        input: Option<PathBuf>,
+    },
+    Bench(bench::BenchOptions),
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let command = match args.next().as_deref() {
+        Some("list") => Command::List,
+        Some("bench") => return bench::parse_options(args).map(Command::Bench),
+        Some("run") => {
+            let day = match args.next().as_deref() {
+                Some("all") => None,
+                Some(day) => Some(parse_day(day)?),
+                None => return Err("Missing the day to run".to_owned()),
+            };
+            let mut part = None;
+            let mut input = None;
+            while let Some(flag) = args.next() {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("Missing a value after {flag}"))?;
+                match flag.as_str() {
+                    "--part" => match value.as_str() {
+                        "1" => part = Some(1),
+                        "2" => part = Some(2),
+                        _ => return Err(format!("There is no part {value:?}, only 1 and 2")),
+                    },
+                    "--input" => input = Some(PathBuf::from(value)),
+                    _ => return Err(format!("Unknown option {flag:?}")),
+                }
+            }
+            if day.is_none() && input.is_some() {
+                return Err("An input file can only be given when running a single day".to_owned());
+            }
+            Command::Run { day, part, input }
+        }
+        Some(other) => return Err(format!("Unknown command {other:?}")),
+        None => return Err("Missing a command".to_owned()),
+    };
+    if let Some(extra) = args.next() {
+        return Err(format!("Unexpected argument {extra:?}"));
+    }
+    Ok(command)
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    let number: u8 = day
+        .parse()
+        .map_err(|_| format!("{day:?} is not a day number"))?;
+    if DAYS.iter().any(|solver| solver.day() == number) {
+        Ok(number)
+    } else {
+        Err(format!("Day {number} has not been solved yet"))
+    }
+}
+
+fn run_day(solver: &Solver, part: Option<u8>, input: Option<&Path>) -> Result<(), String> {
+    let text = read_input(solver.day(), input).map_err(|err| err.to_string())?;
+    let parsed = solver.parse(&text);
+    println!("Day {:02}", solver.day());
+    if part != Some(2) {
+        println!("Part 1:\n{}", parsed.part_1());
+    }
+    if part != Some(1) {
+        println!("Part 2:\n{}", parsed.part_2());
+    }
+    Ok(())
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::List => {
+            for solver in DAYS {
+                println!("Day {:02}", solver.day());
+            }
+            Ok(())
+        }
+        Command::Run { day, part, input } => DAYS
+            .iter()
+            .filter(|solver| day.is_none_or(|number| solver.day() == number))
+            .try_for_each(|solver| run_day(solver, part, input.as_deref())),
+        Command::Bench(options) => bench::run(&options),
+    }
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(message) = run(command) {
+        eprintln!("{message}");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(str::to_owned))
+    }
+
+    #[test]
+    fn aoc_test_days_in_order() {
+        assert!(DAYS.windows(2).all(|pair| pair[0].day() < pair[1].day()));
+    }
+
+    #[test]
+    fn aoc_test_parse_args() {
+        assert_eq!(parse("list"), Ok(Command::List));
+        assert_eq!(
+            parse("run 6 --part 2 --input example.txt"),
+            Ok(Command::Run {
+                day: Some(6),
+                part: Some(2),
+                input: Some(PathBuf::from("example.txt")),
+            })
+        );
+        assert_eq!(
+            parse("run all"),
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: None,
+            })
+        );
+        assert_eq!(
+            parse("run 1 --input -"),
+            Ok(Command::Run {
+                day: Some(1),
+                part: None,
+                input: Some(PathBuf::from("-")),
+            })
+        );
+        assert!(parse("run 26").is_err());
+        assert!(parse("run 6 --part 3").is_err());
+        assert!(parse("run all --input example.txt").is_err());
+        assert!(parse("run 6 --part").is_err());
+        assert_eq!(
+            parse("bench all"),
+            Ok(Command::Bench(bench::BenchOptions::default()))
+        );
+        assert!(parse("").is_err());
+    }
+}
@@ -0,0 +1,297 @@
+use crate::grid::Grid;
+use crate::position::Position;
+
+/// A grid of single bits, packed 64 to a word, with the same shape semantics as [`Grid<T>`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    height: usize,
+    width: usize,
+}
+
+impl BitGrid {
+    /// A grid of `height` rows and `width` columns with every bit clear.
+    ///
+    /// As with [`Grid`], a grid without cells has the shape `(0, 0)`.
+    #[must_use]
+    pub fn new(height: usize, width: usize) -> Self {
+        let (height, width) = if height == 0 || width == 0 {
+            (0, 0)
+        } else {
+            (height, width)
+        };
+        Self {
+            words: vec![0; (height * width).div_ceil(64)],
+            height,
+            width,
+        }
+    }
+
+    /// A grid of the same shape as `grid`, with the bits set where `is_set` holds.
+    pub fn from_grid<T>(grid: &Grid<T>, is_set: impl Fn(&T) -> bool) -> Self {
+        let mut bits = Self::new(grid.height(), grid.width());
+        for position in grid.find_all(is_set) {
+            bits.set(&position);
+        }
+        bits
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows and columns, as in [`Grid::shape`].
+    #[must_use]
+    pub const fn shape(&self) -> Position {
+        Position::new(self.height as u64, self.width as u64)
+    }
+
+    fn index(&self, position: &Position) -> Option<usize> {
+        let Position { row, col } = position;
+        let row = usize::try_from(*row).ok()?;
+        let col = usize::try_from(*col).ok()?;
+        (row < self.height && col < self.width).then(|| row * self.width + col)
+    }
+
+    /// Returns `true` if `position` lies inside the grid.
+    #[must_use]
+    pub fn contains(&self, position: &Position) -> bool {
+        self.index(position).is_some()
+    }
+
+    /// Returns `true` if the bit at `position` is set; positions outside the grid are clear.
+    #[must_use]
+    pub fn get(&self, position: &Position) -> bool {
+        self.index(position)
+            .is_some_and(|index| self.words[index / 64] & (1 << (index % 64)) != 0)
+    }
+
+    /// Sets the bit at `position`, returning `true` if it was clear before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` lies outside the grid.
+    pub fn set(&mut self, position: &Position) -> bool {
+        let index = self
+            .index(position)
+            .expect("Can only set bits inside the grid");
+        let word = &mut self.words[index / 64];
+        let mask = 1 << (index % 64);
+        let was_clear = *word & mask == 0;
+        *word |= mask;
+        was_clear
+    }
+
+    /// Clears the bit at `position`, returning `true` if it was set before.
+    pub fn clear(&mut self, position: &Position) -> bool {
+        let Some(index) = self.index(position) else {
+            return false;
+        };
+        let word = &mut self.words[index / 64];
+        let mask = 1 << (index % 64);
+        let was_set = *word & mask != 0;
+        *word &= !mask;
+        was_set
+    }
+
+    /// The number of set bits.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Clears every bit, keeping the shape.
+    pub fn clear_all(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Iterates over the positions of the set bits in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.height as u64)
+            .flat_map(move |row| (0..self.width as u64).map(move |col| Position::new(row, col)))
+            .filter(|position| self.get(position))
+    }
+}
+
+/// A grid holding a fixed number of flag bits per cell, such as one per [`Direction`].
+///
+/// [`Direction`]: crate::direction::Direction
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct FlagGrid {
+    /// Each cell's flags sit side by side in a row `flags` times as wide.
+    bits: BitGrid,
+    flags: usize,
+}
+
+impl FlagGrid {
+    /// A grid of `height` rows and `width` columns with `flags` clear flags per cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `flags` is zero.
+    #[must_use]
+    pub fn new(height: usize, width: usize, flags: usize) -> Self {
+        assert!(flags > 0, "Cells need at least one flag");
+        Self {
+            bits: BitGrid::new(height, width * flags),
+            flags,
+        }
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.bits.height
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.bits.width / self.flags
+    }
+
+    #[must_use]
+    pub const fn shape(&self) -> Position {
+        Position::new(self.height() as u64, self.width() as u64)
+    }
+
+    #[must_use]
+    pub const fn contains(&self, position: &Position) -> bool {
+        position.is_within(&self.shape())
+    }
+
+    fn flag_position(&self, position: &Position, flag: usize) -> Option<Position> {
+        assert!(
+            flag < self.flags,
+            "Flag {flag} does not exist, cells have {} flags",
+            self.flags
+        );
+        self.contains(position)
+            .then(|| Position::new(position.row, position.col * self.flags as u64 + flag as u64))
+    }
+
+    /// Returns `true` if `flag` is set at `position`; positions outside the grid are clear.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `flag` is not less than the number of flags per cell.
+    #[must_use]
+    pub fn get(&self, position: &Position, flag: usize) -> bool {
+        self.flag_position(position, flag)
+            .is_some_and(|bit| self.bits.get(&bit))
+    }
+
+    /// Sets `flag` at `position`, returning `true` if it was clear before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` lies outside the grid, or `flag` is not less than the number of
+    /// flags per cell.
+    pub fn set(&mut self, position: &Position, flag: usize) -> bool {
+        let bit = self
+            .flag_position(position, flag)
+            .expect("Can only set flags inside the grid");
+        self.bits.set(&bit)
+    }
+
+    /// Clears `flag` at `position`, returning `true` if it was set before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `flag` is not less than the number of flags per cell.
+    pub fn clear(&mut self, position: &Position, flag: usize) -> bool {
+        self.flag_position(position, flag)
+            .is_some_and(|bit| self.bits.clear(&bit))
+    }
+
+    /// Returns `true` if any flag is set at `position`.
+    #[must_use]
+    pub fn any(&self, position: &Position) -> bool {
+        (0..self.flags).any(|flag| self.get(position, flag))
+    }
+
+    /// The number of cells with at least one flag set.
+    #[must_use]
+    pub fn count_cells(&self) -> usize {
+        let Position { row, col } = self.shape();
+        (0..row)
+            .flat_map(|row| (0..col).map(move |col| Position::new(row, col)))
+            .filter(|position| self.any(position))
+            .count()
+    }
+
+    /// The number of set flags over all cells.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.bits.count_ones()
+    }
+
+    /// Clears every flag, keeping the shape.
+    pub fn clear_all(&mut self) {
+        self.bits.clear_all();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bit_grid_set_and_clear() {
+        let mut bits = BitGrid::from_grid(&Grid::parse_grid("#..\n..#", |c| c), |c| *c == '#');
+        assert_eq!(bits.shape(), Position::new(2, 3));
+        assert!(bits.get(&Position::new(1, 2)));
+        assert!(!bits.get(&Position::new(5, 5)));
+        assert!(bits.set(&Position::new(0, 1)));
+        assert!(!bits.set(&Position::new(0, 1)));
+        assert_eq!(bits.count_ones(), 3);
+        assert!(bits.clear(&Position::new(0, 0)));
+        assert!(!bits.clear(&Position::new(0, 0)));
+        assert_eq!(
+            bits.iter().collect::<Vec<_>>(),
+            [Position::new(0, 1), Position::new(1, 2)]
+        );
+        bits.clear_all();
+        assert_eq!(bits.count_ones(), 0);
+
+        let mut large = BitGrid::new(130, 130);
+        large.set(&Position::new(129, 129));
+        assert_eq!(large.iter().collect::<Vec<_>>(), [Position::new(129, 129)]);
+    }
+
+    #[test]
+    fn bit_grid_empty_shapes() {
+        for (height, width) in [(3, 0), (0, 3), (0, 0)] {
+            let shape = Grid::filled(height, width, false).shape();
+            assert_eq!(BitGrid::new(height, width).shape(), shape);
+            assert_eq!(FlagGrid::new(height, width, 4).shape(), shape);
+        }
+        assert_eq!(BitGrid::new(3, 0).shape(), Position::new(0, 0));
+        assert!(!BitGrid::new(3, 0).contains(&Position::new(1, 0)));
+    }
+
+    #[test]
+    fn flag_grid_flags() {
+        let mut flags = FlagGrid::new(3, 5, 4);
+        assert_eq!(flags.shape(), Position::new(3, 5));
+        assert!(flags.set(&Position::new(2, 4), 3));
+        assert!(flags.set(&Position::new(2, 4), 0));
+        assert!(!flags.set(&Position::new(2, 4), 3));
+        assert!(flags.set(&Position::new(0, 0), 1));
+        assert!(flags.get(&Position::new(2, 4), 3));
+        assert!(!flags.get(&Position::new(2, 3), 3));
+        assert!(!flags.get(&Position::new(3, 0), 0));
+        assert_eq!((flags.count_ones(), flags.count_cells()), (3, 2));
+        assert!(flags.clear(&Position::new(2, 4), 0));
+        assert!(flags.any(&Position::new(2, 4)));
+        flags.clear_all();
+        assert!(!flags.any(&Position::new(2, 4)));
+    }
+}
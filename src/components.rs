@@ -0,0 +1,127 @@
+use std::collections::VecDeque;
+
+use crate::direction::Neighbourhood;
+use crate::grid::Grid;
+use crate::position::Position;
+
+/// The connected components of a [`Grid<T>`], numbered from 0 in reading order of their first
+/// cell.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Components {
+    labels: Grid<usize>,
+    cells: Vec<Vec<Position>>,
+}
+
+impl Components {
+    /// The label of the component holding each cell of the grid.
+    #[must_use]
+    pub const fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    #[must_use]
+    pub fn label_of(&self, position: &Position) -> Option<usize> {
+        self.labels.get(position).copied()
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells of the component labelled `label`, starting with its first cell in reading order.
+    #[must_use]
+    pub fn cells(&self, label: usize) -> Option<&[Position]> {
+        self.cells.get(label).map(Vec::as_slice)
+    }
+
+    /// Iterates over the cells of every component, in label order.
+    pub fn iter(&self) -> impl Iterator<Item = &[Position]> {
+        self.cells.iter().map(Vec::as_slice)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Labels the groups of cells reachable from one another through `neighbourhood` steps
+    /// between cells for which `connects` holds.
+    ///
+    /// `connects` should be an equivalence, such as equality of plant types; the grid is
+    /// flood-filled breadth-first, so components may be as large as the grid itself.
+    pub fn components(
+        &self,
+        neighbourhood: Neighbourhood,
+        connects: impl Fn(&T, &T) -> bool,
+    ) -> Components {
+        const UNLABELLED: usize = usize::MAX;
+        let mut labels = Grid::filled(self.height(), self.width(), UNLABELLED);
+        let mut cells: Vec<Vec<Position>> = Vec::new();
+        let mut queue = VecDeque::new();
+        for (start, _value) in self.to_indexed_iterator() {
+            if labels.get(&start) != Some(&UNLABELLED) {
+                continue;
+            }
+            let label = cells.len();
+            let mut component = Vec::new();
+            labels.set(&start, label);
+            queue.push_back(start);
+            while let Some(position) = queue.pop_front() {
+                component.push(position);
+                let Some(value) = self.get(&position) else {
+                    continue;
+                };
+                for (_direction, neighbour, other) in self.neighbours(&position, neighbourhood) {
+                    if labels.get(&neighbour) == Some(&UNLABELLED) && connects(value, other) {
+                        labels.set(&neighbour, label);
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+            cells.push(component);
+        }
+        Components { labels, cells }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn grid_components() {
+        let grid = Grid::parse_grid("aab\nbab\nbba", |c| c);
+        let components = grid.components(Neighbourhood::Orthogonal, |a, b| a == b);
+        assert_eq!(components.len(), 4);
+        assert_eq!(
+            components.labels(),
+            &Grid::new(vec![vec![0, 0, 1], vec![2, 0, 1], vec![2, 2, 3]])
+        );
+        assert_eq!(
+            components.cells(1),
+            Some(&[0, 1].map(|row| Position::new(row, 2))[..])
+        );
+        assert_eq!(components.label_of(&Position::new(2, 1)), Some(2));
+        assert_eq!(
+            components.iter().map(<[Position]>::len).collect::<Vec<_>>(),
+            [3, 2, 3, 1]
+        );
+
+        let king = grid.components(Neighbourhood::King, |a, b| a == b);
+        assert_eq!(
+            king.iter().map(<[Position]>::len).collect::<Vec<_>>(),
+            [4, 5]
+        );
+    }
+
+    #[test]
+    fn grid_components_large_region() {
+        let grid = Grid::new(vec![vec![0u8; 300]; 300]);
+        let components = grid.components(Neighbourhood::Orthogonal, |a, b| a == b);
+        assert_eq!(components.len(), 1);
+        assert_eq!(components.cells(0).map(<[Position]>::len), Some(90_000));
+    }
+}
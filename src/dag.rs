@@ -0,0 +1,278 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{self, Display};
+
+use crate::grid::Grid;
+use crate::position::Position;
+
+/// A number of paths, added up as paths from neighbouring cells are combined.
+pub trait PathCount: Clone {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add_paths(&mut self, other: &Self);
+}
+
+impl PathCount for u64 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn add_paths(&mut self, other: &Self) {
+        *self = self
+            .checked_add(*other)
+            .expect("Too many paths for u64, count them with u128 or BigCount");
+    }
+}
+
+impl PathCount for u128 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn add_paths(&mut self, other: &Self) {
+        *self = self
+            .checked_add(*other)
+            .expect("Too many paths for u128, count them with BigCount");
+    }
+}
+
+/// An unsigned integer that grows as needed, for path counts beyond even `u128`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct BigCount {
+    /// Base 2^64 digits, least significant first, without trailing zeros.
+    limbs: Vec<u64>,
+}
+
+impl From<u64> for BigCount {
+    fn from(value: u64) -> Self {
+        Self {
+            limbs: if value == 0 { Vec::new() } else { vec![value] },
+        }
+    }
+}
+
+impl PathCount for BigCount {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn one() -> Self {
+        Self::from(1)
+    }
+
+    fn add_paths(&mut self, other: &Self) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+        let mut carry = false;
+        for (index, limb) in self.limbs.iter_mut().enumerate() {
+            let (sum, overflowed) =
+                limb.overflowing_add(other.limbs.get(index).copied().unwrap_or(0));
+            let (sum, carried) = sum.overflowing_add(u64::from(carry));
+            *limb = sum;
+            carry = overflowed || carried;
+        }
+        if carry {
+            self.limbs.push(1);
+        }
+    }
+}
+
+impl Display for BigCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        // Peel off 19 decimal digits at a time, least significant first.
+        let mut remaining = self.limbs.clone();
+        let mut chunks = Vec::new();
+        while !remaining.is_empty() {
+            let mut remainder: u128 = 0;
+            for limb in remaining.iter_mut().rev() {
+                let value = (remainder << 64) | u128::from(*limb);
+                *limb = u64::try_from(value / u128::from(CHUNK)).map_err(|_| fmt::Error)?;
+                remainder = value % u128::from(CHUNK);
+            }
+            chunks.push(remainder);
+            while remaining.last() == Some(&0) {
+                remaining.pop();
+            }
+        }
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((most_significant, rest)) => {
+                write!(f, "{most_significant}")?;
+                rest.iter()
+                    .rev()
+                    .try_for_each(|chunk| write!(f, "{chunk:019}"))
+            }
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Counts, for every cell, the distinct paths from it to any cell for which `is_target`
+    /// holds, following orthogonal steps for which `edge` holds.
+    ///
+    /// A path may pass through one target on its way to another; both endings are counted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `edge` allows walking in a circle.
+    pub fn count_paths<C: PathCount>(
+        &self,
+        edge: impl Fn(&T, &T) -> bool,
+        is_target: impl Fn(&T) -> bool,
+    ) -> Grid<C> {
+        let mut counts = Grid::filled(self.height(), self.width(), C::zero());
+        for position in self.topological_order(&edge).iter().rev() {
+            let Some(value) = self.get(position) else {
+                continue;
+            };
+            let mut count = if is_target(value) {
+                C::one()
+            } else {
+                C::zero()
+            };
+            for (_direction, next, to) in self.orthogonal_neighbours(position) {
+                if edge(value, to) {
+                    if let Some(from_next) = counts.get(&next) {
+                        count.add_paths(from_next);
+                    }
+                }
+            }
+            counts.set(position, count);
+        }
+        counts
+    }
+
+    /// Collects, for every cell, the cells for which `is_target` holds that can be reached
+    /// from it by orthogonal steps for which `edge` holds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `edge` allows walking in a circle.
+    pub fn reachable_targets(
+        &self,
+        edge: impl Fn(&T, &T) -> bool,
+        is_target: impl Fn(&T) -> bool,
+    ) -> Grid<HashSet<Position>> {
+        let mut reachable = Grid::filled(self.height(), self.width(), HashSet::new());
+        for position in self.topological_order(&edge).iter().rev() {
+            let Some(value) = self.get(position) else {
+                continue;
+            };
+            let mut targets = HashSet::new();
+            if is_target(value) {
+                targets.insert(*position);
+            }
+            for (_direction, next, to) in self.orthogonal_neighbours(position) {
+                if edge(value, to) {
+                    if let Some(from_next) = reachable.get(&next) {
+                        targets.extend(from_next.iter().copied());
+                    }
+                }
+            }
+            reachable.set(position, targets);
+        }
+        reachable
+    }
+
+    /// Orders the cells so that every `edge` leads from an earlier cell to a later one.
+    fn topological_order(&self, edge: &impl Fn(&T, &T) -> bool) -> Vec<Position> {
+        let successors = |position: &Position| {
+            let from = self.get(position);
+            self.orthogonal_neighbours(position)
+                .filter(move |(_direction, _next, to)| from.is_some_and(|from| edge(from, to)))
+                .map(|(_direction, next, _to)| next)
+        };
+        let mut incoming = Grid::filled(self.height(), self.width(), 0_usize);
+        for (position, _value) in self.to_indexed_iterator() {
+            for next in successors(&position) {
+                if let Some(count) = incoming.get_mut(&next) {
+                    *count += 1;
+                }
+            }
+        }
+        let mut ready: VecDeque<Position> = incoming
+            .to_indexed_iterator()
+            .filter(|(_position, count)| **count == 0)
+            .map(|(position, _count)| position)
+            .collect();
+        let mut order = Vec::with_capacity(self.height() * self.width());
+        while let Some(position) = ready.pop_front() {
+            for next in successors(&position) {
+                if let Some(count) = incoming.get_mut(&next) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.push_back(next);
+                    }
+                }
+            }
+            order.push(position);
+        }
+        assert_eq!(
+            order.len(),
+            self.height() * self.width(),
+            "Path counting needs edges that never lead back to where they started"
+        );
+        order
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn heights() -> Grid<u32> {
+        Grid::parse_grid("0123\n1234\n2345", |c| c.to_digit(10).unwrap_or(0))
+    }
+
+    #[test]
+    fn grid_count_paths() {
+        let grid = heights();
+        let uphill = |from: &u32, to: &u32| *to == from + 1;
+        let counts: Grid<u64> = grid.count_paths(uphill, |height| *height == 5);
+        assert_eq!(counts.get(&Position::new(0, 0)), Some(&10));
+        assert_eq!(counts.get(&Position::new(1, 2)), Some(&2));
+        let reachable = grid.reachable_targets(uphill, |height| *height >= 4);
+        assert_eq!(
+            reachable.get(&Position::new(0, 0)).map(HashSet::len),
+            Some(3)
+        );
+        assert_eq!(
+            reachable.get(&Position::new(0, 3)).map(HashSet::len),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn big_counts() {
+        let mut count = BigCount::from(u64::MAX);
+        count.add_paths(&BigCount::one());
+        assert_eq!(count.to_string(), "18446744073709551616");
+        let doubled = count.clone();
+        count.add_paths(&doubled);
+        assert_eq!(
+            count.to_string(),
+            (u128::from(u64::MAX) * 2 + 2).to_string()
+        );
+        assert_eq!(BigCount::zero().to_string(), "0");
+
+        let grid = Grid::new(vec![(0..80).collect::<Vec<u32>>(), (1..81).collect()]);
+        let uphill = |from: &u32, to: &u32| *to == from + 1;
+        let counts: Grid<BigCount> = grid.count_paths(uphill, |height| *height == 80);
+        assert_eq!(counts.get(&Position::new(0, 0)), Some(&BigCount::from(80)));
+    }
+
+    #[test]
+    #[should_panic(expected = "never lead back")]
+    fn grid_count_paths_rejects_cycles() {
+        let _: Grid<u64> = heights().count_paths(|_, _| true, |_| true);
+    }
+}
@@ -0,0 +1,22 @@
+//! The solved puzzles, one module per day.
+//!
+//! The list below is the registry: each `dayNN::DayNN` entry both declares the `dayNN` module
+//! and adds its solution to [`DAYS`], so a new day is added with a single line here.
+
+crate::register_days! {
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    day13::Day13,
+    day14::Day14,
+    day15::Day15,
+}
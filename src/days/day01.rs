@@ -1,3 +1,4 @@
+use crate::solution::{Answer, Solution};
 use counter::Counter;
 use nom::character::complete::{digit1, multispace1};
 use nom::combinator::{all_consuming, map_res, recognize};
@@ -5,37 +6,52 @@ use nom::sequence::separated_pair;
 use nom::IResult;
 use std::iter::zip;
 
-fn part_1(text: &str) -> u64 {
-    let (mut first, mut last): (Vec<u64>, Vec<u64>) = text
-        .lines()
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// Splits the two columns into the left and right lists.
+///
+/// # Panics
+///
+/// Panics if a line is not two whole numbers.
+#[must_use]
+pub fn parse(text: &str) -> (Vec<u64>, Vec<u64>) {
+    text.lines()
         .map(parse_tuple_ints)
         .map(|a| a.unwrap())
         .map(|(_, tup)| tup)
-        .collect();
+        .collect()
+}
+
+#[must_use]
+pub fn part_1((first, last): &(Vec<u64>, Vec<u64>)) -> u64 {
+    let mut first = first.clone();
+    let mut last = last.clone();
     first.sort_unstable();
     last.sort_unstable();
     zip(first, last).fold(0, |acc, (a, b)| acc + a.abs_diff(b))
 }
 
-fn part_2(text: &str) -> u64 {
-    let (first, last): (Vec<u64>, Vec<u64>) = text
-        .lines()
-        .map(parse_tuple_ints)
-        .map(|a| a.unwrap())
-        .map(|(_, tup)| tup)
-        .collect();
+#[must_use]
+pub fn part_2((first, last): &(Vec<u64>, Vec<u64>)) -> u64 {
     let frequency_map: Counter<_> = last.iter().collect();
-    first
-        .into_iter()
-        .map(|i| i * frequency_map[&i] as u64)
-        .sum()
-}
-
-fn main() {
-    let text: String =
-        std::fs::read_to_string("data/01.txt").expect("Couldn't read file at hard-coded path!");
-    println!("Part 1:\n{}", part_1(&text));
-    println!("Part 2:\n{}", part_2(&text));
+    first.iter().map(|i| i * frequency_map[&i] as u64).sum()
 }
 
 fn parse_tuple_ints(text: &str) -> IResult<&str, (u64, u64)> {
@@ -48,9 +64,6 @@ fn parse_tuple_ints(text: &str) -> IResult<&str, (u64, u64)> {
 
 #[cfg(test)]
 mod test {
-
-    use crate::parse_tuple_ints;
-
     use super::*;
     const INPUT: &str = "\
 3   4
@@ -73,11 +86,11 @@ mod test {
 
     #[test]
     fn test_input_parse() {
-        assert_eq!(part_1(INPUT), 11);
+        assert_eq!(part_1(&parse(INPUT)), 11);
     }
 
     #[test]
     fn part_2_test() {
-        assert_eq!(part_2(INPUT), 31);
+        assert_eq!(part_2(&parse(INPUT)), 31);
     }
 }
@@ -1,3 +1,4 @@
+use crate::solution::{Answer, Solution};
 use nom::branch::alt;
 use nom::character::complete::{digit1, space1};
 use nom::combinator::{map_res, recognize};
@@ -5,13 +6,6 @@ use nom::multi::many1;
 use nom::sequence::terminated;
 use nom::IResult;
 
-fn main() {
-    let text: String =
-        std::fs::read_to_string("data/02.txt").expect("Couldn't read file at hard-coded path!");
-    println!("Part 1:\n{}", part_1(&text));
-    println!("Part 2:\n{}", part_2(&text));
-}
-
 fn parse_levels(line: &str) -> IResult<&str, Vec<i64>> {
     many1(alt((
         terminated(map_res(recognize(digit1), str::parse), space1),
@@ -27,25 +21,48 @@ fn part_1_criterion(line: &[i64]) -> bool {
         })
 }
 
-fn part_1(text: &str) -> usize {
-    text.lines()
-        .map(|l| {
-            let (_, parser_results) = parse_levels(l).unwrap();
-            parser_results
-        })
-        .filter(|l| part_1_criterion(l))
-        .count()
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
 }
 
-fn part_2(text: &str) -> usize {
-    let levels: Vec<Vec<i64>> = text
-        .lines()
+/// Parses one report of levels per line.
+///
+/// # Panics
+///
+/// Panics if a line holds anything but space separated numbers.
+#[must_use]
+pub fn parse(text: &str) -> Vec<Vec<i64>> {
+    text.lines()
         .map(|l| {
             let (_, level_ints) = parse_levels(l).unwrap();
             level_ints
         })
-        .collect();
-    let easy_count: usize = levels.iter().filter(|l| part_1_criterion(l)).count();
+        .collect()
+}
+
+#[must_use]
+pub fn part_1(levels: &[Vec<i64>]) -> usize {
+    levels.iter().filter(|l| part_1_criterion(l)).count()
+}
+
+#[must_use]
+pub fn part_2(levels: &[Vec<i64>]) -> usize {
+    let easy_count: usize = part_1(levels);
     let one_off_count: usize = levels
         .iter()
         .filter(|l| !part_1_criterion(l))
@@ -97,11 +114,11 @@ mod test {
 
     #[test]
     fn day_2_part_1_test() {
-        assert_eq!(part_1(INPUT), 2);
+        assert_eq!(part_1(&parse(INPUT)), 2);
     }
 
     #[test]
     fn day_2_part_2_test() {
-        assert_eq!(part_2(INPUT), 4);
+        assert_eq!(part_2(&parse(INPUT)), 4);
     }
 }
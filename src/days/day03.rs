@@ -1,3 +1,4 @@
+use crate::solution::{Answer, Solution};
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{anychar, digit1};
@@ -7,13 +8,6 @@ use nom::multi::{many0, many_till};
 use nom::sequence::{delimited, separated_pair, terminated};
 use nom::IResult;
 
-fn main() {
-    let text: String =
-        std::fs::read_to_string("data/03.txt").expect("Couldn't read file at hard-coded path!");
-    println!("Part 1:\n{}", part_1(&text));
-    println!("Part 2:\n{}", part_2(&text));
-}
-
 fn mul_parser(line: &str) -> IResult<&str, (u64, u64)> {
     delimited(
         tag(r"mul("),
@@ -25,26 +19,9 @@ fn mul_parser(line: &str) -> IResult<&str, (u64, u64)> {
         tag(r")"),
     )(line)
 }
-fn parse_single_with_front_mul(line: &str) -> IResult<&str, (u64, u64)> {
-    many_till(anychar, mul_parser)(line).map(|(rem, (_chars, tup))| (rem, tup))
-}
-
-fn parse_many_muls(line: &str) -> IResult<&str, Vec<(u64, u64)>> {
-    terminated(many0(parse_single_with_front_mul), rest)(line)
-}
 
-fn part_1(text: &str) -> u64 {
-    text.lines()
-        .map(parse_many_muls)
-        .map(|res| {
-            let (_rem, v) = res.unwrap();
-            v.iter().map(|(a, b)| a * b).sum::<u64>()
-        })
-        .sum()
-}
-
-#[derive(Debug, PartialEq)]
-enum Expression {
+#[derive(Debug, PartialEq, Eq)]
+pub enum Expression {
     Mul(u64, u64),
     Cond(bool),
 }
@@ -68,9 +45,51 @@ fn parse_many_expressions(text: &str) -> IResult<&str, Vec<Expression>> {
     terminated(many0(remove_lead_till_expression), rest)(text)
 }
 
-fn part_2(text: &str) -> u64 {
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Expression>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// Picks the instructions out of the corrupted memory, skipping everything else.
+///
+/// # Panics
+///
+/// Panics if a multiplication does not fit in `u64`.
+#[must_use]
+pub fn parse(text: &str) -> Vec<Expression> {
     let (_rest, v) = parse_many_expressions(text).unwrap();
-    v.iter()
+    v
+}
+
+#[must_use]
+pub fn part_1(expressions: &[Expression]) -> u64 {
+    expressions
+        .iter()
+        .map(|expression| match expression {
+            Expression::Mul(x, y) => x * y,
+            Expression::Cond(_) => 0,
+        })
+        .sum()
+}
+
+#[must_use]
+pub fn part_2(expressions: &[Expression]) -> u64 {
+    expressions
+        .iter()
         .fold(
             (true, 0),
             |(b, e): (bool, u64), item: &Expression| match item {
@@ -94,13 +113,32 @@ mod test {
 xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
 
     #[test]
-    fn get_tuples() {
+    fn get_expressions() {
         assert_eq!(
-            parse_many_muls(INPUT),
-            Ok(("", vec![(2, 4), (5, 5), (11, 8), (8, 5)]))
+            parse(INPUT),
+            vec![
+                Expression::Mul(2, 4),
+                Expression::Cond(false),
+                Expression::Mul(5, 5),
+                Expression::Mul(11, 8),
+                Expression::Cond(true),
+                Expression::Mul(8, 5),
+            ]
         );
     }
 
+    #[test]
+    fn get_tuples() {
+        let muls: Vec<_> = parse(INPUT)
+            .into_iter()
+            .filter_map(|expression| match expression {
+                Expression::Mul(a, b) => Some((a, b)),
+                Expression::Cond(_) => None,
+            })
+            .collect();
+        assert_eq!(muls, vec![(2, 4), (5, 5), (11, 8), (8, 5)]);
+    }
+
     #[test]
     fn mul_parser_test() {
         assert_eq!(mul_parser("mul(23,24)"), Ok(("", (23u64, 24u64))));
@@ -109,14 +147,14 @@ xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
     #[test]
     fn preceded_mul_parser_test() {
         assert_eq!(
-            parse_single_with_front_mul("1234c0291c4i019muc12904mnulc12940mul(43,43)"),
-            Ok(("", (43, 43)))
+            parse("1234c0291c4i019muc12904mnulc12940mul(43,43)"),
+            vec![Expression::Mul(43, 43)]
         );
     }
 
     #[test]
     fn day_3_part_1_test() {
-        assert_eq!(part_1(INPUT), 161);
+        assert_eq!(part_1(&parse(INPUT)), 161);
     }
 
     #[test]
@@ -135,6 +173,6 @@ xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
 
     #[test]
     fn day_3_part_2() {
-        assert_eq!(part_2(INPUT), 48);
+        assert_eq!(part_2(&parse(INPUT)), 48);
     }
 }
@@ -0,0 +1,98 @@
+use std::convert::identity;
+
+use crate::grid::Grid;
+use crate::pattern::Pattern;
+use crate::solution::{Answer, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// Reads the word search as a grid of letters.
+#[must_use]
+pub fn parse(text: &str) -> Grid<char> {
+    Grid::parse_grid(text, identity)
+}
+
+fn letters(c: char) -> Option<char> {
+    (c != '.').then_some(c)
+}
+
+#[must_use]
+pub fn part_1(grid: &Grid<char>) -> usize {
+    let straight = Pattern::parse("XMAS", letters).under_all();
+    let diagonal = Pattern::parse(
+        "\
+X...
+.M..
+..A.
+...S",
+        letters,
+    )
+    .under_all();
+    straight.find_all(grid).count() + diagonal.find_all(grid).count()
+}
+
+#[must_use]
+pub fn part_2(grid: &Grid<char>) -> usize {
+    let x_mas = Pattern::parse(
+        "\
+M.S
+.A.
+M.S",
+        letters,
+    )
+    .under_all();
+    x_mas.find_all(grid).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    const INPUT: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn day_4_small_puzzle_test() {
+        let test: &str = "\
+XMAS
+MMAA
+AMAA
+SAXS";
+        assert_eq!(part_1(&parse(test)), 3);
+    }
+
+    #[test]
+    fn day_4_part_1_test() {
+        assert_eq!(part_1(&parse(INPUT)), 18);
+    }
+
+    #[test]
+    fn day_4_part_2_test() {
+        assert_eq!(part_2(&parse(INPUT)), 9);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::solution::{Answer, Solution};
 use nom::branch::alt;
 use nom::character::complete::digit1;
 use nom::combinator::map_res;
@@ -6,11 +7,33 @@ use nom::sequence::{separated_pair, terminated};
 use nom::{bytes::complete::tag, IResult};
 use std::collections::{HashMap, HashSet};
 
-fn main() {
-    let text: String =
-        std::fs::read_to_string("data/05.txt").expect("Couldn't read file at hard-coded path!");
-    println!("Part 1:\n{}", part_1(&text));
-    println!("Part 2:\n{}", part_2(&text));
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = (Rules, Vec<Vec<u64>>);
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// For each page, the pages that must come after it.
+pub type Rules = HashMap<u64, HashSet<u64>>;
+
+/// Reads the ordering rules and the updates to check against them.
+#[must_use]
+pub fn parse(input: &str) -> (Rules, Vec<Vec<u64>>) {
+    let (rule_block, update_block) = separate_rule_block_and_update_block(input);
+    (extract_rules(rule_block), extract_updates(update_block))
 }
 
 fn separate_rule_block_and_update_block(text: &str) -> (&str, &str) {
@@ -58,14 +81,15 @@ fn part_1_filter(vec: &[u64], part_1_rules: &HashMap<u64, HashSet<u64>>) -> bool
     })
 }
 
-fn part_1(input: &str) -> u64 {
-    let (rule_block, update_block) = separate_rule_block_and_update_block(input);
-    let rules = extract_rules(rule_block);
-    let updates = extract_updates(update_block);
+/// # Panics
+///
+/// Panics if an update has no pages.
+#[must_use]
+pub fn part_1((rules, updates): &(Rules, Vec<Vec<u64>>)) -> u64 {
     updates
         .iter()
         .filter_map(|update| {
-            if part_1_filter(update, &rules) {
+            if part_1_filter(update, rules) {
                 None
             } else {
                 Some(*update.get(update.len() / 2).unwrap())
@@ -87,14 +111,15 @@ fn sort_vec(vec: &[u64], rules: &HashMap<u64, HashSet<u64>>) -> Vec<u64> {
     }
     vec.to_vec()
 }
-fn part_2(input: &str) -> u64 {
-    let (rule_block, update_block) = separate_rule_block_and_update_block(input);
-    let rules = extract_rules(rule_block);
-    let updates = extract_updates(update_block);
+/// # Panics
+///
+/// Panics if an update has no pages.
+#[must_use]
+pub fn part_2((rules, updates): &(Rules, Vec<Vec<u64>>)) -> u64 {
     updates
         .iter()
-        .filter(|vec| part_1_filter(vec, &rules))
-        .map(|update| sort_vec(update, &rules))
+        .filter(|vec| part_1_filter(vec, rules))
+        .map(|update| sort_vec(update, rules))
         .map(|sorted_vec| *sorted_vec.get(sorted_vec.len() / 2).unwrap())
         .sum()
 }
@@ -207,7 +232,7 @@ mod test {
 
     #[test]
     fn day_05_test_part_1() {
-        assert_eq!(part_1(INPUT), 143);
+        assert_eq!(part_1(&parse(INPUT)), 143);
     }
 
     #[test]
@@ -226,6 +251,6 @@ mod test {
 
     #[test]
     fn day_05_test_part_2() {
-        assert_eq!(part_2(INPUT), 123);
+        assert_eq!(part_2(&parse(INPUT)), 123);
     }
 }
@@ -0,0 +1,199 @@
+use std::convert::identity;
+
+use crate::bit_grid::{BitGrid, FlagGrid};
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solution::{Answer, Solution};
+use rayon::prelude::*;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = (Guard, BitGrid);
+
+    fn parse(input: &str) -> Self::Input {
+        parser_for_part_1(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Guard {
+    pub position: Position,
+    pub direction: Direction,
+}
+
+impl Guard {
+    fn step(&self, max_positions: &Position) -> Option<Position> {
+        self.position
+            .step(self.direction)
+            .filter(|next| next.is_within(max_positions))
+    }
+}
+
+/// Reads the guard's starting place and the obstacles around them.
+///
+/// # Panics
+///
+/// Panics if the map has no guard on it.
+#[must_use]
+pub fn parser_for_part_1(text: &str) -> (Guard, BitGrid) {
+    let grid = Grid::parse_grid(text, identity);
+    let guard = grid
+        .find(|c| *c == '^')
+        .map(|position| Guard {
+            position,
+            direction: Direction::Up,
+        })
+        .expect("He should definitely be there");
+    (guard, BitGrid::from_grid(&grid, |c| *c == '#'))
+}
+
+fn part_1_step_guard(guard: &Guard, obstacles: &BitGrid) -> Option<Guard> {
+    guard.step(&obstacles.shape()).map(|next_pos| {
+        if obstacles.get(&next_pos) {
+            Guard {
+                direction: guard.direction.turn_right(),
+                ..*guard
+            }
+        } else {
+            Guard {
+                position: next_pos,
+                ..*guard
+            }
+        }
+    })
+}
+
+#[must_use]
+pub fn part_1((guard, obstacles): &(Guard, BitGrid)) -> usize {
+    let mut guard = *guard;
+    let mut sites_visited = BitGrid::new(obstacles.height(), obstacles.width());
+    sites_visited.set(&guard.position);
+    while let Some(new_guard) = part_1_step_guard(&guard, obstacles) {
+        guard = new_guard;
+        sites_visited.set(&guard.position);
+    }
+    sites_visited.count_ones()
+}
+
+const fn facing(guard: &Guard) -> usize {
+    guard
+        .direction
+        .orthogonal_index()
+        .expect("The guard only turns by quarter turns from facing up")
+}
+
+/// Walks the guard until they leave the map, or return to a place they already faced the same way.
+fn guard_loops(mut guard: Guard, obstacles: &BitGrid, sites_visited: &mut FlagGrid) -> bool {
+    sites_visited.clear_all();
+    sites_visited.set(&guard.position, facing(&guard));
+    while let Some(new_guard) = part_1_step_guard(&guard, obstacles) {
+        guard = new_guard;
+        if !sites_visited.set(&guard.position, facing(&guard)) {
+            return true;
+        }
+    }
+    false
+}
+
+#[must_use]
+pub fn part_2((guard, obstacles): &(Guard, BitGrid)) -> usize {
+    let guard = *guard;
+    let candidates: Vec<Position> = (0..obstacles.shape().row)
+        .flat_map(|row| (0..obstacles.shape().col).map(move |col| Position::new(row, col)))
+        .filter(|position| !obstacles.get(position) && *position != guard.position)
+        .collect();
+    candidates
+        .into_par_iter()
+        .map_init(
+            || {
+                let sites_visited = FlagGrid::new(
+                    obstacles.height(),
+                    obstacles.width(),
+                    Direction::ORTHOGONAL.len(),
+                );
+                (obstacles.clone(), sites_visited)
+            },
+            |(new_obstacles, sites_visited), candidate| {
+                new_obstacles.set(&candidate);
+                let loops = guard_loops(guard, new_obstacles, sites_visited);
+                new_obstacles.clear(&candidate);
+                loops
+            },
+        )
+        .filter(|loops| *loops)
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    const INPUT: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+    #[test]
+    fn day_6_test_part_1_parser() {
+        let (guard, obstacles) = parser_for_part_1(INPUT);
+        assert_eq!(obstacles.shape(), Position::new(10, 10));
+        assert_eq!(
+            (guard, obstacles.iter().collect::<Vec<_>>()),
+            (
+                Guard {
+                    position: Position::new(6, 4),
+                    direction: Direction::Up
+                },
+                vec![
+                    Position::new(0, 4),
+                    Position::new(1, 9),
+                    Position::new(3, 2),
+                    Position::new(4, 7),
+                    Position::new(6, 1),
+                    Position::new(7, 8),
+                    Position::new(8, 0),
+                    Position::new(9, 6),
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn day_6_guard() {
+        let max = Position::new(2, 2);
+        assert_eq!(
+            Guard {
+                position: Position::new(1, 1),
+                direction: Direction::Up
+            }
+            .step(&max),
+            Some(Position::new(0, 1))
+        );
+    }
+
+    #[test]
+    fn day_6_part_1() {
+        assert_eq!(part_1(&parser_for_part_1(INPUT)), 41);
+    }
+
+    #[test]
+    fn day_6_part_2() {
+        assert_eq!(part_2(&parser_for_part_1(INPUT)), 6);
+    }
+}
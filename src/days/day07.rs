@@ -1,3 +1,4 @@
+use crate::solution::{Answer, Solution};
 use itertools::{repeat_n, Itertools};
 use nom::bytes::complete::tag;
 use nom::character::complete::digit1;
@@ -6,11 +7,36 @@ use nom::multi::many1;
 use nom::sequence::{preceded, separated_pair};
 use nom::IResult;
 
-fn main() {
-    let text: String =
-        std::fs::read_to_string("data/07.txt").expect("Couldn't read file at hard-coded path!");
-    println!("Part 1:\n{}", part_1(&text));
-    println!("Part 2:\n{}", part_2(&text));
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// Reads each equation as its test value and the numbers to combine.
+///
+/// # Panics
+///
+/// Panics if a line is not a test value followed by a colon and numbers.
+#[must_use]
+pub fn parse(input: &str) -> Vec<(u64, Vec<u64>)> {
+    input
+        .lines()
+        .map(|line| parser(line).expect("AOC hardcoded format").1)
+        .collect()
 }
 
 fn u64_parser(input: &str) -> IResult<&str, u64> {
@@ -52,9 +78,7 @@ impl BinOp for Append {
     }
 }
 
-fn part_1_single_line(line: &str, ops_set: &[&dyn BinOp]) -> Option<u64> {
-    let (_rest, (target, nums)): (&str, (u64, Vec<u64>)) =
-        parser(line).expect("AOC hardcoded format");
+fn part_1_single_line((target, nums): &(u64, Vec<u64>), ops_set: &[&dyn BinOp]) -> Option<u64> {
     if repeat_n(ops_set.iter(), nums.len() - 1)
         .multi_cartesian_product()
         .any(|ops| {
@@ -65,27 +89,29 @@ fn part_1_single_line(line: &str, ops_set: &[&dyn BinOp]) -> Option<u64> {
                     let current_op = ops.get(n).expect("These lengths must match");
                     current_op.apply_op(acc, *elm)
                 })
-                == target
+                == *target
         })
     {
-        Some(target)
+        Some(*target)
     } else {
         None
     }
 }
 
-fn part_1(input: &str) -> u64 {
+#[must_use]
+pub fn part_1(equations: &[(u64, Vec<u64>)]) -> u64 {
     let part_1_binops: Vec<&dyn BinOp> = vec![&Add, &Mul];
-    input
-        .lines()
+    equations
+        .iter()
         .filter_map(|l| part_1_single_line(l, &part_1_binops))
         .sum()
 }
 
-fn part_2(input: &str) -> u64 {
+#[must_use]
+pub fn part_2(equations: &[(u64, Vec<u64>)]) -> u64 {
     let part_2_binops: Vec<&dyn BinOp> = vec![&Add, &Mul, &Append];
-    input
-        .lines()
+    equations
+        .iter()
         .filter_map(|l| part_1_single_line(l, &part_2_binops))
         .sum()
 }
@@ -108,14 +134,14 @@ mod test {
     fn day_7_test_line_for_part_1() {
         let set: Vec<&dyn BinOp> = vec![&Add, &Mul];
         assert_eq!(
-            part_1_single_line(INPUT.lines().next().unwrap(), &set),
+            part_1_single_line(&parse(INPUT)[0], &set),
             Some(190)
         );
     }
 
     #[test]
     fn day_7_part_1() {
-        assert_eq!(part_1(INPUT), 3749);
+        assert_eq!(part_1(&parse(INPUT)), 3749);
     }
 
     #[test]
@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+use std::iter::successors;
+
+use crate::position::Position;
+use crate::solution::{Answer, Solution};
+use crate::sparse_grid::SparseGrid;
+use itertools::Itertools;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = (SparseGrid<char>, Position);
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// Reads the antennas along with the size of the map they are on.
+#[must_use]
+pub fn parse(input: &str) -> (SparseGrid<char>, Position) {
+    (parse_antennas(input), get_grid_size(input))
+}
+
+/// Reads the antennas by position, each labelled with its frequency.
+///
+/// # Panics
+///
+/// Panics if the map holds anything but `.` and alphanumeric frequencies.
+#[must_use]
+pub fn parse_antennas(input: &str) -> SparseGrid<char> {
+    SparseGrid::parse(input, |c| match c {
+        '.' => None,
+        a if a.is_alphanumeric() => Some(a),
+        _ => panic!(),
+    })
+}
+
+fn get_grid_size(input: &str) -> Position {
+    Position::new(
+        input.lines().count().try_into().unwrap(),
+        input.lines().next().unwrap().len().try_into().unwrap(),
+    )
+}
+
+fn possible_antinodes(
+    left_antenna: &Position,
+    right_antenna: &Position,
+    maxes: &Position,
+) -> Vec<Position> {
+    let delta = *right_antenna - *left_antenna;
+    [*left_antenna - delta, *right_antenna + delta]
+        .into_iter()
+        .flatten()
+        .filter(|pos| pos.row < maxes.row && pos.col < maxes.col)
+        .collect()
+}
+
+fn day_8_engine(
+    (antennas, maxes): &(SparseGrid<char>, Position),
+    antinodes_from_antenna_pairs_fun: &dyn Fn(&Position, &Position, &Position) -> Vec<Position>,
+) -> usize {
+    antennas
+        .iter()
+        .map(|(at, frequency)| {
+            (
+                *frequency,
+                Position::try_from(at).expect("Antennas are parsed from the map"),
+            )
+        })
+        .into_group_map()
+        .values()
+        .flat_map(|antenna_positions| {
+            antenna_positions
+                .iter()
+                .combinations(2)
+                .flat_map(|ants| antinodes_from_antenna_pairs_fun(ants[0], ants[1], maxes))
+                .collect::<Vec<Position>>()
+        })
+        .fold(
+            HashSet::new(),
+            |mut acc: HashSet<Position>, position: Position| {
+                acc.insert(position);
+                acc
+            },
+        )
+        .len()
+}
+#[must_use]
+pub fn part_1(input: &(SparseGrid<char>, Position)) -> usize {
+    day_8_engine(input, &possible_antinodes)
+}
+
+fn possible_antinodes_part_2(
+    left_antenna: &Position,
+    right_antenna: &Position,
+    maxes: &Position,
+) -> Vec<Position> {
+    let delta = *right_antenna - *left_antenna;
+    let inside = |pos: &Position| pos.row < maxes.row && pos.col < maxes.col;
+    let mut res: Vec<Position> = Vec::new();
+    res.extend(successors(Some(*left_antenna), |pos| {
+        (*pos - delta).filter(inside)
+    }));
+    res.extend(successors(Some(*right_antenna), |pos| {
+        (*pos + delta).filter(inside)
+    }));
+    res
+}
+
+#[must_use]
+pub fn part_2(input: &(SparseGrid<char>, Position)) -> usize {
+    day_8_engine(input, &possible_antinodes_part_2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::offset::Offset;
+    const INPUT: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn day_8_test_parse_antennas() {
+        assert_eq!(
+            parse_antennas(INPUT).iter().collect::<Vec<_>>(),
+            vec![
+                (Offset::new(1, 8), &'0'),
+                (Offset::new(2, 5), &'0'),
+                (Offset::new(3, 7), &'0'),
+                (Offset::new(4, 4), &'0'),
+                (Offset::new(5, 6), &'A'),
+                (Offset::new(8, 8), &'A'),
+                (Offset::new(9, 9), &'A'),
+            ]
+        );
+    }
+
+    #[test]
+    fn day_8_antinonode_test() {
+        assert_eq!(
+            possible_antinodes(
+                &Position::new(4, 4),
+                &Position::new(6, 6),
+                &Position::new(15, 15)
+            ),
+            vec![Position::new(2, 2), Position::new(8, 8)]
+        );
+        assert_eq!(
+            possible_antinodes(
+                &Position::new(1, 1),
+                &Position::new(5, 5),
+                &Position::new(10, 10)
+            ),
+            vec![Position::new(9, 9)]
+        );
+    }
+
+    #[test]
+    fn day_8_part_1() {
+        assert_eq!(part_1(&parse(INPUT)), 14);
+    }
+
+    #[test]
+    fn day_8_part_1_on_a_wide_map() {
+        // Columns are bounded by the map's width, not its height.
+        assert_eq!(part_1(&parse("a.a...\n......")), 1);
+    }
+
+    #[test]
+    fn day_8_part_2() {
+        assert_eq!(part_2(&parse(INPUT)), 34);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::solution::{Answer, Solution};
 use nom::bytes::complete::take;
 use nom::combinator::map_res;
 use nom::multi::many1;
@@ -5,15 +6,27 @@ use nom::IResult;
 
 use std::{collections::VecDeque, iter::repeat_n, ops::Div};
 
-fn main() {
-    let text: String =
-        std::fs::read_to_string("data/09.txt").expect("Couldn't read file at hard-coded path!");
-    println!("Part 1:\n{}", part_1(&text));
-    println!("Part 2:\n{}", part_2(&text));
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = VecDeque<Block>;
+
+    fn parse(input: &str) -> Self::Input {
+        first_parse_part_1(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
 }
 
-#[derive(Debug, PartialEq)]
-enum BlockType {
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BlockType {
     File { index: u64 },
     Empty,
 }
@@ -36,17 +49,23 @@ impl BlockType {
     }
 }
 
-#[derive(Debug, PartialEq)]
-struct Block {
-    variant: BlockType,
-    count: u64,
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Block {
+    pub variant: BlockType,
+    pub count: u64,
 }
 
 fn u64_parser(c: &str) -> IResult<&str, u64> {
     map_res(take(1u64), str::parse::<u64>)(c)
 }
 
-fn first_parse_part_1(input: &str) -> VecDeque<Block> {
+/// Reads the disk map as alternating file and free space blocks.
+///
+/// # Panics
+///
+/// Panics if the disk map is not a string of digits.
+#[must_use]
+pub fn first_parse_part_1(input: &str) -> VecDeque<Block> {
     many1(u64_parser)(input)
         .expect("Hardcoded input")
         .1
@@ -77,7 +96,7 @@ fn compact_scattered_disk(mut blocks: VecDeque<Block>) -> Vec<u64> {
     let mut res: Vec<u64> = Vec::new();
     if blocks.back().unwrap().variant == BlockType::Empty {
         blocks.pop_back();
-    };
+    }
     blocks.retain(|b| b.count > 0);
     while !blocks.is_empty() {
         match blocks
@@ -88,30 +107,28 @@ fn compact_scattered_disk(mut blocks: VecDeque<Block>) -> Vec<u64> {
             BlockType::File { index } => {
                 res.push(index);
             }
-            BlockType::Empty => {
-                match blocks.back() {
-                    Some(block) => {
-                        if block.variant == BlockType::Empty {
-                            blocks.pop_back();
-                        }
-                        match blocks.back_mut() {
-                            Some(Block { count, variant }) => {
-                                match variant {
-                                    BlockType::File { index } => res.push(*index),
-                                    BlockType::Empty => panic!(),
-                                };
-                                if *count == 1 {
-                                    blocks.pop_back();
-                                } else {
-                                    *count -= 1;
-                                }
+            BlockType::Empty => match blocks.back() {
+                Some(block) => {
+                    if block.variant == BlockType::Empty {
+                        blocks.pop_back();
+                    }
+                    match blocks.back_mut() {
+                        Some(Block { count, variant }) => {
+                            match variant {
+                                BlockType::File { index } => res.push(*index),
+                                BlockType::Empty => panic!(),
+                            }
+                            if *count == 1 {
+                                blocks.pop_back();
+                            } else {
+                                *count -= 1;
                             }
-                            None => return res,
                         }
+                        None => return res,
                     }
-                    None => return res,
-                };
-            }
+                }
+                None => return res,
+            },
         }
         match blocks.get_mut(0) {
             Some(Block { count: 1, .. }) => {
@@ -119,13 +136,14 @@ fn compact_scattered_disk(mut blocks: VecDeque<Block>) -> Vec<u64> {
             }
             Some(Block { count, .. }) => *count -= 1,
             None => return res,
-        };
+        }
     }
     res
 }
 
-fn part_1(input: &str) -> u64 {
-    compact_scattered_disk(first_parse_part_1(input))
+#[must_use]
+pub fn part_1(disc: &VecDeque<Block>) -> u64 {
+    compact_scattered_disk(disc.clone())
         .iter()
         .enumerate()
         .map(|(n, e): (usize, &u64)| (n as u64) * e)
@@ -196,9 +214,9 @@ fn part_2_flatten_disc_for_hash(disc: &VecDeque<Block>) -> Vec<Part2Index> {
         })
 }
 
-fn part_2(input: &str) -> u64 {
-    let disc = first_parse_part_1(input);
-    let moved_disc = try_move_file_to_space(disc);
+#[must_use]
+pub fn part_2(disc: &VecDeque<Block>) -> u64 {
+    let moved_disc = try_move_file_to_space(disc.clone());
     part_2_flatten_disc_for_hash(&moved_disc)
         .iter()
         .enumerate()
@@ -312,7 +330,7 @@ mod test {
 
     #[test]
     fn day_9_part_1() {
-        assert_eq!(part_1(INPUT), 1928);
+        assert_eq!(part_1(&first_parse_part_1(INPUT)), 1928);
     }
 
     #[test]
@@ -370,6 +388,6 @@ mod test {
 
     #[test]
     fn day_9_part_2() {
-        assert_eq!(part_2(INPUT), 2858);
+        assert_eq!(part_2(&first_parse_part_1(INPUT)), 2858);
     }
 }
@@ -0,0 +1,117 @@
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solution::{Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Grid<u64>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_height_map(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// # Panics
+///
+/// Panics if a height is not a single digit.
+#[must_use]
+pub fn parse_height_map(input: &str) -> Grid<u64> {
+    Grid::try_parse_grid(input, |c| c.to_digit(10).map(u64::from))
+        .unwrap_or_else(|err| panic!("Invalid height map: {err}"))
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn uphill(from: &u64, to: &u64) -> bool {
+    *to == from + 1
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_summit(height: &u64) -> bool {
+    *height == 9
+}
+
+fn trailheads(grid: &Grid<u64>) -> impl Iterator<Item = Position> + '_ {
+    grid.find_all(|height| *height == 0)
+}
+
+#[must_use]
+pub fn part_1(grid: &Grid<u64>) -> u64 {
+    let summits = grid.reachable_targets(uphill, is_summit);
+    trailheads(grid)
+        .filter_map(|pos| summits.get(&pos))
+        .map(|reachable| reachable.len() as u64)
+        .sum()
+}
+
+#[must_use]
+pub fn part_2(grid: &Grid<u64>) -> u64 {
+    let ratings: Grid<u64> = grid.count_paths(uphill, is_summit);
+    trailheads(grid).filter_map(|pos| ratings.get(&pos)).sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+    const INPUT: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn day_10_test_grid_parser() {
+        assert_eq!(
+            parse_height_map(INPUT),
+            Grid::new(vec![
+                vec![8, 9, 0, 1, 0, 1, 2, 3,],
+                vec![7, 8, 1, 2, 1, 8, 7, 4,],
+                vec![8, 7, 4, 3, 0, 9, 6, 5,],
+                vec![9, 6, 5, 4, 9, 8, 7, 4,],
+                vec![4, 5, 6, 7, 8, 9, 0, 3,],
+                vec![3, 2, 0, 1, 9, 0, 1, 2,],
+                vec![0, 1, 3, 2, 9, 8, 0, 1,],
+                vec![1, 0, 4, 5, 6, 7, 3, 2,],
+            ])
+        );
+    }
+
+    #[test]
+    fn day_10_test_trail_ends_and_ratings() {
+        let grid = parse_height_map(INPUT);
+        let summits = grid.reachable_targets(uphill, is_summit);
+        let ratings: Grid<u64> = grid.count_paths(uphill, is_summit);
+        let trail_ends = |position: Position| summits.get(&position).map(HashSet::len);
+        assert_eq!(trail_ends(Position::new(0, 2)), Some(5));
+        assert_eq!(trail_ends(Position::new(0, 4)), Some(6));
+        assert_eq!(trail_ends(Position::new(2, 4)), Some(5));
+        assert_eq!(trail_ends(Position::new(4, 6)), Some(3));
+        assert_eq!(trail_ends(Position::new(5, 2)), Some(1));
+        assert_eq!(ratings.get(&Position::new(0, 2)), Some(&20));
+        assert_eq!(ratings.get(&Position::new(5, 2)), Some(&1));
+    }
+
+    #[test]
+    fn day_10_test_part_1() {
+        assert_eq!(part_1(&parse_height_map(INPUT)), 36);
+    }
+
+    #[test]
+    fn day_10_part_2() {
+        assert_eq!(part_2(&parse_height_map(INPUT)), 81);
+    }
+}
@@ -1,30 +1,50 @@
+use crate::solution::{Answer, Solution};
 use nom::{
     branch::alt, bytes::complete::tag, character::complete::digit1, combinator::map_res,
     multi::many1, sequence::preceded, IResult,
 };
 use std::collections::HashMap;
 
-fn main() {
-    let text: String =
-        std::fs::read_to_string("data/11.txt").expect("Couldn't read file at hard-coded path!");
-    println!("Part 1:\n{}", part_1(&text));
-    println!("Part 2:\n{}", part_2(&text));
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Stone>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_stones(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
-struct Stone {
-    engraving: u64,
+pub struct Stone {
+    pub engraving: u64,
 }
 
 impl Stone {
-    const fn new(engraving: u64) -> Self {
+    #[must_use]
+    pub const fn new(engraving: u64) -> Self {
         Self { engraving }
     }
 
-    fn blinked(&self) -> Vec<Self> {
+    /// The stones this one turns into when the observer blinks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a stone's engraving grows past `u64`.
+    #[must_use]
+    pub fn blinked(&self) -> Vec<Self> {
         if self.engraving == 0 {
             vec![Self::new(1)]
-        } else if self.engraving.to_string().len() % 2 == 0 {
+        } else if self.engraving.to_string().len().is_multiple_of(2) {
             let number_string = self.engraving.to_string();
             vec![
                 Self::new(
@@ -48,16 +68,19 @@ fn u64_parser(input: &str) -> IResult<&str, u64> {
     map_res(digit1, str::parse)(input)
 }
 
-fn parse_stones(input: &str) -> Vec<Stone> {
+/// # Panics
+///
+/// Panics if the engravings are not numbers separated by single spaces.
+#[must_use]
+pub fn parse_stones(input: &str) -> Vec<Stone> {
     let (_rest, stones) = many1(alt((u64_parser, preceded(tag(" "), u64_parser))))(input)
         .expect("Hardcoded string from AOC");
     stones.iter().map(|d| Stone::new(*d)).collect()
 }
 
-fn day_11_driver(input: &str, blinks: usize) -> u64 {
-    let stones = parse_stones(input);
+fn day_11_driver(stones: &[Stone], blinks: usize) -> u64 {
     let mut stone_map: HashMap<Stone, u64> =
-        stones.into_iter().fold(HashMap::new(), |mut acc, stone| {
+        stones.iter().cloned().fold(HashMap::new(), |mut acc, stone| {
             *acc.entry(stone).or_default() += 1;
             acc
         });
@@ -80,12 +103,15 @@ fn day_11_driver(input: &str, blinks: usize) -> u64 {
     }
     stone_map.values().sum()
 }
-fn part_1(input: &str) -> u64 {
-    day_11_driver(input, 25)
+
+#[must_use]
+pub fn part_1(stones: &[Stone]) -> u64 {
+    day_11_driver(stones, 25)
 }
 
-fn part_2(input: &str) -> u64 {
-    day_11_driver(input, 75)
+#[must_use]
+pub fn part_2(stones: &[Stone]) -> u64 {
+    day_11_driver(stones, 75)
 }
 
 #[cfg(test)]
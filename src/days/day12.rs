@@ -0,0 +1,132 @@
+use std::convert::identity;
+
+use crate::direction::Neighbourhood;
+use crate::grid::Grid;
+use crate::region::Region;
+use crate::solution::{Answer, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<Region>;
+
+    fn parse(input: &str) -> Self::Input {
+        find_regions(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+type Day12Grid = Grid<char>;
+
+fn parse_char_grid(input: &str) -> Day12Grid {
+    Grid::parse_grid(input, identity)
+}
+
+#[must_use]
+pub fn find_regions(input: &str) -> Vec<Region> {
+    parse_char_grid(input)
+        .components(Neighbourhood::Orthogonal, |a, b| a == b)
+        .regions()
+        .collect()
+}
+
+#[must_use]
+pub fn part_1(regions: &[Region]) -> usize {
+    regions
+        .iter()
+        .map(|region| region.area() * region.perimeter())
+        .sum()
+}
+
+#[must_use]
+pub fn part_2(regions: &[Region]) -> usize {
+    regions
+        .iter()
+        .map(|region| region.area() * region.sides())
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position::Position;
+    const INPUT: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn day_12_test_char_grid_parser() {
+        assert_eq!(
+            parse_char_grid(INPUT),
+            Grid::new(INPUT.lines().map(|l| l.chars().collect()).collect())
+        );
+    }
+
+    #[test]
+    fn day_12_find_regions_test() {
+        let regions = find_regions(INPUT);
+        assert_eq!(regions.len(), 11);
+        assert_eq!(regions[0].area(), 12);
+        assert!(regions[1].contains(&Position::new(0, 4)));
+        assert_eq!(regions[1].area(), 4);
+    }
+
+    #[test]
+    fn day_12_part_1() {
+        let input2 = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO";
+        assert_eq!(part_1(&find_regions(input2)), 772);
+        assert_eq!(part_1(&find_regions(INPUT)), 1930);
+    }
+
+    #[test]
+    fn day_12_part_2() {
+        let grid1 = "\
+AAAA
+BBCD
+BBCC
+EEEC";
+        assert_eq!(part_2(&find_regions(grid1)), 80);
+        let grid2 = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE";
+        assert_eq!(find_regions(grid2).len(), 3);
+        assert_eq!(part_2(&find_regions(grid2)), 236);
+        assert_eq!(
+            part_2(&find_regions(
+                "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA"
+            )),
+            368
+        );
+        assert_eq!(part_2(&find_regions(INPUT)), 1206);
+    }
+}
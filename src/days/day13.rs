@@ -1,3 +1,4 @@
+use crate::solution::{Answer, Solution};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -7,11 +8,32 @@ use nom::{
     IResult,
 };
 
-fn main() {
-    let text: String =
-        std::fs::read_to_string("data/13.txt").expect("Couldn't read file at hard-coded path!");
-    println!("Part 1:\n{}", part_1(&text));
-    println!("Part 2:\n{}", part_2(&text));
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Vec<(f64, f64)>>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// Reads each claw machine as the moves of buttons A and B followed by the prize location.
+#[must_use]
+pub fn parse(input: &str) -> Vec<Vec<(f64, f64)>> {
+    input
+        .split("\n\n")
+        .map(|block| block.lines().map(parse_line).collect())
+        .collect()
 }
 
 fn digit_parser(digit_str: &str) -> IResult<&str, f64> {
@@ -32,8 +54,7 @@ fn parse_line(line: &str) -> (f64, f64) {
 }
 
 #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
-fn part_1_single_block(block: &str) -> Option<u64> {
-    let nums: Vec<(f64, f64)> = block.lines().map(parse_line).collect();
+fn part_1_single_block(nums: &[(f64, f64)]) -> Option<u64> {
     // Matrix inversion by hand
     let a = nums[0].0;
     let b = nums[1].0;
@@ -53,8 +74,7 @@ fn part_1_single_block(block: &str) -> Option<u64> {
 }
 
 #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
-fn part_2_single_block(block: &str) -> Option<u64> {
-    let nums: Vec<(f64, f64)> = block.lines().map(parse_line).collect();
+fn part_2_single_block(nums: &[(f64, f64)]) -> Option<u64> {
     // Matrix inversion by hand
     let a = nums[0].0 as i128;
     let b = nums[1].0 as i128;
@@ -72,12 +92,21 @@ fn part_2_single_block(block: &str) -> Option<u64> {
         None
     }
 }
-fn part_1(input: &str) -> u64 {
-    input.split("\n\n").filter_map(part_1_single_block).sum()
+
+#[must_use]
+pub fn part_1(machines: &[Vec<(f64, f64)>]) -> u64 {
+    machines
+        .iter()
+        .filter_map(|machine| part_1_single_block(machine))
+        .sum()
 }
 
-fn part_2(input: &str) -> u64 {
-    input.split("\n\n").filter_map(part_2_single_block).sum()
+#[must_use]
+pub fn part_2(machines: &[Vec<(f64, f64)>]) -> u64 {
+    machines
+        .iter()
+        .filter_map(|machine| part_2_single_block(machine))
+        .sum()
 }
 
 #[cfg(test)]
@@ -103,6 +132,6 @@ Prize: X=18641, Y=10279
 
     #[test]
     fn day_13_test_part_1() {
-        assert_eq!(part_1(INPUT), 480);
+        assert_eq!(part_1(&parse(INPUT)), 480);
     }
 }
@@ -0,0 +1,188 @@
+use crate::grid::Grid;
+use crate::offset::Offset;
+use crate::position::Position;
+use crate::solution::{Answer, Solution};
+use crate::sparse_grid::SparseGrid;
+use crate::torus::Torus;
+use nom::{
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator::{map_res, opt, recognize},
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+use std::iter::successors;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_robots(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pub position: Position,
+    pub velocity: Offset,
+}
+
+fn parse_i64(input: &str) -> IResult<&str, i64> {
+    let (i, number) = map_res(recognize(preceded(opt(tag("-")), digit1)), |s| {
+        str::parse(s)
+    })(input)?;
+
+    Ok((i, number))
+}
+
+fn parse_robot_line(line: &str) -> Robot {
+    let (_rest, ((pos_x, pos_y), (vel_x, vel_y))) = separated_pair(
+        separated_pair(preceded(tag("p="), parse_i64), tag(","), parse_i64),
+        tag(" v="),
+        separated_pair(parse_i64, tag(","), parse_i64),
+    )(line)
+    .expect("AOC hardcoded pattern");
+    Robot {
+        position: Position::try_from(Offset::new(pos_y, pos_x))
+            .expect("Robots start inside the room"),
+        velocity: Offset::new(vel_y, vel_x),
+    }
+}
+
+#[must_use]
+pub fn parse_robots(input: &str) -> Vec<Robot> {
+    input.lines().map(parse_robot_line).collect()
+}
+
+/// Moves every robot one second on, wrapping around the edges of the room.
+#[must_use]
+pub fn step_robots(robots: &[Robot], room: &Torus) -> Vec<Robot> {
+    robots
+        .iter()
+        .map(|robot| Robot {
+            position: room.add(robot.position, robot.velocity),
+            ..*robot
+        })
+        .collect()
+}
+
+fn part_1_driver(robots: &[Robot], room: &Torus) -> u64 {
+    let mut robots = robots.to_vec();
+    for _ in 0..100 {
+        robots = step_robots(&robots, room);
+    }
+    quadrant_count_robots(&robots, room)
+        .to_indexed_iterator()
+        .map(|(_quadrant, count)| count)
+        .product()
+}
+
+fn quadrant_count_robots(robots: &[Robot], room: &Torus) -> Grid<u64> {
+    room.quadrants()
+        .count(robots.iter().map(|robot| robot.position))
+}
+
+fn room() -> Torus {
+    Torus::new(Position::new(103, 101))
+}
+
+#[must_use]
+pub fn part_1(robots: &[Robot]) -> u64 {
+    part_1_driver(robots, &room())
+}
+
+/// The first second, within one period of the room, at which no two robots share a tile.
+///
+/// Every robot is back where it started once the room's area in seconds has passed, so later
+/// seconds would only repeat earlier ones.
+fn first_second_apart(robots: &[Robot], room: &Torus) -> Option<usize> {
+    let period = room.shape().row * room.shape().col;
+    successors(Some(robots.to_vec()), |robots: &Vec<Robot>| {
+        Some(step_robots(robots, room))
+    })
+    .take(usize::try_from(period).expect("The room fits in memory"))
+    .position(|robots| {
+        let mut robot_map = SparseGrid::new();
+        robots.iter().all(|robot| {
+            robot_map
+                .insert(
+                    Offset::try_from(robot.position).expect("Robots stay inside the room"),
+                    '#',
+                )
+                .is_none()
+        })
+    })
+}
+
+/// The second at which the robots draw the tree, found heuristically.
+///
+/// The puzzle gives no rule for spotting the tree, so this used to show each second at which
+/// no two robots overlap and let a person pick the picture. The tree is drawn at the first such
+/// second, so that is the answer taken here without looking at it.
+///
+/// # Panics
+///
+/// Panics if the robots repeat their dance without ever standing apart.
+#[must_use]
+pub fn part_2(robots: &[Robot]) -> usize {
+    first_second_apart(robots, &room()).expect("The robots never all stand apart")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+    const INPUT: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn day_14_test_part_1_driver() {
+        let room = Torus::new(Position::new(7, 11));
+        let mut robots = parse_robots(INPUT);
+        for _ in 0..100 {
+            robots = step_robots(&robots, &room);
+        }
+        assert_eq!(
+            quadrant_count_robots(&robots, &room),
+            Grid::new(vec![vec![1, 3], vec![4, 1]])
+        );
+        assert_eq!(part_1_driver(&parse_robots(INPUT), &room), 12);
+    }
+
+    #[test]
+    fn day_14_test_first_second_apart() {
+        let room = Torus::new(Position::new(7, 11));
+        let robots = parse_robots(INPUT);
+        let apart = first_second_apart(&robots, &room).expect("The example robots part");
+        let mut stepped = robots.clone();
+        for second in 0..=apart {
+            let positions: HashSet<Position> = stepped.iter().map(|robot| robot.position).collect();
+            assert_eq!(positions.len() == robots.len(), second == apart);
+            stepped = step_robots(&stepped, &room);
+        }
+        let stuck = parse_robots("p=1,1 v=2,3\np=1,1 v=2,3");
+        assert_eq!(first_second_apart(&stuck, &room), None);
+    }
+}
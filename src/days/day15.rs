@@ -0,0 +1,206 @@
+use crate::direction::Direction;
+use crate::glyph::Glyph;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solution::{Answer, Solution};
+use std::collections::VecDeque;
+pub type Day15Grid = Grid<GridValue>;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = (Robot, Day15Grid, VecDeque<Direction>);
+
+    fn parse(input: &str) -> Self::Input {
+        part_1_parser(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(_input: &Self::Input) -> Answer {
+        Answer::Text("Not solved yet".to_owned())
+    }
+}
+
+#[derive(Clone)]
+pub struct Robot {
+    pub position: Position,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GridValue {
+    Wall,
+    Box,
+    Empty,
+    Robot,
+}
+
+impl GridValue {
+    /// Returns `true` if the grid value is [`Robot`].
+    ///
+    /// [`Robot`]: GridValue::Robot
+    #[must_use]
+    const fn is_robot(self) -> bool {
+        matches!(self, Self::Robot)
+    }
+
+    /// Returns `true` if the grid value is [`Box`].
+    ///
+    /// [`Box`]: GridValue::Box
+    #[must_use]
+    const fn is_box(self) -> bool {
+        matches!(self, Self::Box)
+    }
+}
+
+/// Reads the robot's position, the warehouse and the moves to make.
+///
+/// # Panics
+///
+/// Panics if the map has no robot or a move is not one of `^>v<`.
+#[must_use]
+pub fn part_1_parser(input: &str) -> (Robot, Day15Grid, VecDeque<Direction>) {
+    let (grid_block, instructions_block) = input
+        .split_once("\n\n")
+        .expect("Otherwise I did a copy paste error");
+    let grid = parse_grid(grid_block);
+    let robot = Robot {
+        position: grid
+            .find(|value| value.is_robot())
+            .expect("There should be a single robot!"),
+    };
+    let instructions = instructions_block
+        .chars()
+        .filter(|c| *c != '\n')
+        .map(|c| Direction::try_from(c).unwrap_or_else(|err| panic!("Invalid robot move: {err}")))
+        .collect();
+    (robot, grid, instructions)
+}
+
+impl Glyph for GridValue {
+    fn to_glyph(&self) -> char {
+        match self {
+            Self::Wall => '#',
+            Self::Empty => '.',
+            Self::Robot => '@',
+            Self::Box => 'O',
+        }
+    }
+
+    fn from_glyph(glyph: char) -> Option<Self> {
+        match glyph {
+            '#' => Some(Self::Wall),
+            '.' => Some(Self::Empty),
+            '@' => Some(Self::Robot),
+            'O' => Some(Self::Box),
+            _ => None,
+        }
+    }
+}
+
+fn parse_grid(grid_block: &str) -> Grid<GridValue> {
+    Grid::parse_glyphs(grid_block).unwrap_or_else(|err| panic!("Invalid warehouse map: {err}"))
+}
+
+/// Moves the robot one step, pushing any boxes in its way unless a wall stops them.
+pub fn execute_robot_instruction(robot: &mut Robot, grid: &mut Day15Grid, instruction: Direction) {
+    let pushed = grid.push(
+        &robot.position,
+        instruction,
+        |value| value.is_box(),
+        |value| matches!(value, GridValue::Wall),
+    );
+    // A successful push moved the robot along with everything it pushed.
+    if let Some(next) = pushed.and_then(|_| grid.step(&robot.position, instruction)) {
+        robot.position = next;
+    }
+}
+
+fn gps_sum(
+    mut robot: Robot,
+    mut grid: Day15Grid,
+    instructions: &VecDeque<Direction>,
+) -> u64 {
+    for instruction in instructions {
+        execute_robot_instruction(&mut robot, &mut grid, *instruction);
+    }
+    grid.find_all(|value| value.is_box())
+        .fold(0, |acc, pos| acc + 100 * pos.row + pos.col)
+}
+
+#[must_use]
+pub fn part_1((robot, grid, instructions): &(Robot, Day15Grid, VecDeque<Direction>)) -> u64 {
+    gps_sum(robot.clone(), grid.clone(), instructions)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::journal::Journal;
+    const INPUT: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn day_15_test_parsing_and_moves() {
+        let (mut robot, mut grid, instructions) = part_1_parser(INPUT);
+        for instruction in instructions {
+            execute_robot_instruction(&mut robot, &mut grid, instruction);
+        }
+        assert_eq!(
+            grid,
+            parse_grid(
+                "##########
+#.O.O.OOO#
+#........#
+#OO......#
+#OO@.....#
+#O#.....O#
+#O.....OO#
+#O.....OO#
+#OO....OO#
+##########"
+            )
+        );
+    }
+
+    #[test]
+    fn day_15_test_rewinding_moves() {
+        let (mut robot, grid, instructions) = part_1_parser(INPUT);
+        let mut journal = Journal::new(grid.clone());
+        for instruction in &instructions {
+            journal.record(|grid| execute_robot_instruction(&mut robot, grid, *instruction));
+        }
+        let end = journal.grid().clone();
+        assert_eq!(journal.len(), instructions.len());
+        // The first move pushes the box left of the robot one cell further.
+        assert_eq!(journal.diffs()[0].len(), 3);
+        journal.jump_to(0);
+        assert_eq!(journal.grid(), &grid);
+        journal.jump_to(instructions.len());
+        assert_eq!(journal.grid(), &end);
+    }
+}
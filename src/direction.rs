@@ -0,0 +1,207 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use crate::offset::Offset;
+
+/// One of the eight compass directions on a grid where rows grow downwards.
+///
+/// The variants are listed clockwise starting from [`Direction::Up`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four directions sharing an edge with a cell, clockwise from [`Direction::Up`].
+    pub const ORTHOGONAL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+    /// The four directions sharing only a corner with a cell, clockwise from [`Direction::UpRight`].
+    pub const DIAGONAL: [Self; 4] = [Self::UpRight, Self::DownRight, Self::DownLeft, Self::UpLeft];
+    /// All eight directions, clockwise from [`Direction::Up`].
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// The position of this direction in [`Direction::ALL`], for use as a flag or array index.
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The position of this direction in [`Direction::ORTHOGONAL`], or `None` if it is diagonal.
+    #[must_use]
+    pub const fn orthogonal_index(self) -> Option<usize> {
+        if self.is_orthogonal() {
+            Some(self.index() / 2)
+        } else {
+            None
+        }
+    }
+
+    /// Rotates `eighths` eighths of a full turn clockwise.
+    const fn rotated(self, eighths: usize) -> Self {
+        Self::ALL[(self.index() + eighths) % 8]
+    }
+
+    /// Rotates a quarter turn clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        self.rotated(2)
+    }
+
+    /// Rotates a quarter turn counter-clockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        self.rotated(6)
+    }
+
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        self.rotated(4)
+    }
+
+    #[must_use]
+    pub const fn is_orthogonal(self) -> bool {
+        matches!(self, Self::Up | Self::Right | Self::Down | Self::Left)
+    }
+
+    /// The offset of a single step in this direction.
+    #[must_use]
+    pub const fn offset(self) -> Offset {
+        match self {
+            Self::Up => Offset::new(-1, 0),
+            Self::UpRight => Offset::new(-1, 1),
+            Self::Right => Offset::new(0, 1),
+            Self::DownRight => Offset::new(1, 1),
+            Self::Down => Offset::new(1, 0),
+            Self::DownLeft => Offset::new(1, -1),
+            Self::Left => Offset::new(0, -1),
+            Self::UpLeft => Offset::new(-1, -1),
+        }
+    }
+}
+
+/// Which of the cells around a position count as its neighbours.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Neighbourhood {
+    /// The four cells sharing an edge.
+    Orthogonal,
+    /// The four cells sharing only a corner.
+    Diagonal,
+    /// All eight surrounding cells, like the moves of a chess king.
+    King,
+}
+
+impl Neighbourhood {
+    #[must_use]
+    pub const fn directions(self) -> &'static [Direction] {
+        match self {
+            Self::Orthogonal => &Direction::ORTHOGONAL,
+            Self::Diagonal => &Direction::DIAGONAL,
+            Self::King => &Direction::ALL,
+        }
+    }
+}
+
+impl From<Direction> for Offset {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseDirectionError(pub char);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not one of ^>v< or UDLR", self.0)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// Parses arrow characters `^>v<` as well as the letters `UDLR`.
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'U' => Ok(Self::Up),
+            '>' | 'R' => Ok(Self::Right),
+            'v' | 'D' => Ok(Self::Down),
+            '<' | 'L' => Ok(Self::Left),
+            _ => Err(ParseDirectionError(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::position::Position;
+
+    #[test]
+    fn direction_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::UpRight);
+        assert_eq!(Direction::DownRight.reverse(), Direction::UpLeft);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+        }
+        assert!(Direction::ORTHOGONAL.iter().all(|d| d.is_orthogonal()));
+        assert!((0..8).eq(Direction::ALL.map(Direction::index)));
+        assert!((0..4).eq(Direction::ORTHOGONAL.map(|d| d.orthogonal_index().unwrap())));
+        assert_eq!(Direction::UpLeft.orthogonal_index(), None);
+        assert!(!Direction::DIAGONAL.iter().any(|d| d.is_orthogonal()));
+    }
+
+    #[test]
+    fn direction_parsing() {
+        assert_eq!(
+            "^>v<UDLR"
+                .chars()
+                .map(Direction::try_from)
+                .collect::<Result<Vec<_>, _>>(),
+            Ok(vec![
+                Direction::Up,
+                Direction::Right,
+                Direction::Down,
+                Direction::Left,
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+            ])
+        );
+        assert_eq!(Direction::try_from('x'), Err(ParseDirectionError('x')));
+    }
+
+    #[test]
+    fn direction_stepping() {
+        let grid = Grid::parse_grid("ab\ncd", |c| c);
+        let corner = Position::new(0, 1);
+        assert_eq!(corner.step(Direction::Up), None);
+        assert_eq!(corner.step(Direction::Right), Some(Position::new(0, 2)));
+        assert_eq!(grid.step(&corner, Direction::Right), None);
+        assert_eq!(
+            grid.step(&corner, Direction::DownLeft),
+            Some(Position::new(1, 0))
+        );
+    }
+}
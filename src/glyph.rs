@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use crate::grid::{Grid, GridParseError};
+use crate::position::Position;
+
+/// A cell type that can be drawn as, and read back from, a single character.
+pub trait Glyph: Sized {
+    fn to_glyph(&self) -> char;
+
+    fn from_glyph(glyph: char) -> Option<Self>;
+}
+
+impl Glyph for char {
+    fn to_glyph(&self) -> char {
+        *self
+    }
+
+    fn from_glyph(glyph: char) -> Option<Self> {
+        Some(glyph)
+    }
+}
+
+/// Occupied cells are drawn as `#`, free ones as `.`.
+impl Glyph for bool {
+    fn to_glyph(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+
+    fn from_glyph(glyph: char) -> Option<Self> {
+        match glyph {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Writes `height` lines of `width` characters, without a trailing newline.
+pub(crate) fn write_glyph_rows(
+    f: &mut fmt::Formatter<'_>,
+    height: usize,
+    width: usize,
+    glyph_at: impl Fn(usize, usize) -> char,
+) -> fmt::Result {
+    for row in 0..height {
+        if row > 0 {
+            writeln!(f)?;
+        }
+        for col in 0..width {
+            write!(f, "{}", glyph_at(row, col))?;
+        }
+    }
+    Ok(())
+}
+
+impl<T: Glyph> Grid<T> {
+    /// Parses a grid drawn with the same characters [`Display`] renders it with.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GridParseError`] if a character is not a glyph of `T` or the rows are ragged.
+    pub fn parse_glyphs(value: &str) -> Result<Self, GridParseError> {
+        Self::try_parse_grid(value, T::from_glyph)
+    }
+
+    /// Starts an [`Overlay`] for drawing markers on top of this grid.
+    #[must_use]
+    pub fn overlay(&self) -> Overlay<'_, T> {
+        Overlay {
+            grid: self,
+            markers: HashMap::new(),
+        }
+    }
+}
+
+/// Renders one line per row, using [`Glyph::to_glyph`] for every cell.
+impl<T: Glyph> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_glyph_rows(f, self.height(), self.width(), |row, col| {
+            self.row(row).expect("Row is inside the grid")[col].to_glyph()
+        })
+    }
+}
+
+/// A [`Grid<T>`] rendering with extra markers, such as robots or a path, drawn over its cells.
+pub struct Overlay<'a, T> {
+    grid: &'a Grid<T>,
+    markers: HashMap<Position, char>,
+}
+
+impl<T> Overlay<'_, T> {
+    /// Draws `glyph` at `position`, replacing any earlier marker there.
+    #[must_use]
+    pub fn mark(mut self, position: Position, glyph: char) -> Self {
+        self.markers.insert(position, glyph);
+        self
+    }
+
+    /// Draws `glyph` at every one of `positions`.
+    #[must_use]
+    pub fn mark_all(mut self, positions: impl IntoIterator<Item = Position>, glyph: char) -> Self {
+        self.markers
+            .extend(positions.into_iter().map(|position| (position, glyph)));
+        self
+    }
+}
+
+impl<T: Glyph> Display for Overlay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_glyph_rows(f, self.grid.height(), self.grid.width(), |row, col| {
+            let position = Position::new(row as u64, col as u64);
+            self.markers.get(&position).copied().unwrap_or_else(|| {
+                self.grid
+                    .get(&position)
+                    .expect("Position is inside the grid")
+                    .to_glyph()
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAP: &str = "\
+#..#
+.##.
+....";
+
+    #[test]
+    fn glyph_render_round_trip() {
+        let grid: Grid<bool> = Grid::parse_glyphs(MAP).expect("Valid test map");
+        assert_eq!(grid.to_string(), MAP);
+        assert_eq!(Grid::parse_grid(&grid.to_string(), |c| c == '#'), grid);
+        assert_eq!(
+            Grid::<bool>::parse_glyphs("#.\n.x"),
+            Err(GridParseError::UnexpectedChar {
+                line: 2,
+                column: 2,
+                character: 'x',
+                expected_width: 2
+            })
+        );
+    }
+
+    #[test]
+    fn glyph_overlay() {
+        let grid: Grid<bool> = Grid::parse_glyphs(MAP).expect("Valid test map");
+        let path = (0..4).map(|col| Position::new(2, col));
+        assert_eq!(
+            grid.overlay()
+                .mark_all(path, 'o')
+                .mark(Position::new(2, 3), '@')
+                .to_string(),
+            "#..#\n.##.\nooo@"
+        );
+    }
+}
@@ -1,95 +1,490 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use crate::direction::{Direction, Neighbourhood};
+use crate::offset::Offset;
 use crate::position::Position;
-#[derive(Debug, PartialEq, Eq)]
-pub struct Grid<T> {
-    pub data: Vec<Vec<T>>,
+
+/// Why a block of text could not be parsed into a [`Grid<T>`].
+///
+/// Lines and columns are counted from 1, like in an editor.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GridParseError {
+    /// A line did not have the same number of cells as the first line.
+    RaggedRow {
+        line: usize,
+        expected_width: usize,
+        found_width: usize,
+    },
+    /// The cell mapper did not recognise a character.
+    UnexpectedChar {
+        line: usize,
+        column: usize,
+        character: char,
+        expected_width: usize,
+    },
 }
 
-impl<T: Eq> Grid<T> {
-    #[must_use]
-    pub const fn new(data: Vec<Vec<T>>) -> Self {
-        Self { data }
+impl Display for GridParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RaggedRow {
+                line,
+                expected_width,
+                found_width,
+            } => write!(
+                f,
+                "line {line} has {found_width} cells, expected {expected_width}"
+            ),
+            Self::UnexpectedChar {
+                line,
+                column,
+                character,
+                expected_width,
+            } => write!(
+                f,
+                "unexpected character {character:?} at line {line}, column {column} \
+                 (rows are {expected_width} wide)"
+            ),
+        }
     }
+}
+
+impl Error for GridParseError {}
 
-    /// .
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from a vector of rows, flattening them into row-major storage.
     ///
     /// # Panics
     ///
-    /// Panics if usize cannot be cast from input u64s
+    /// Panics if the rows are not all of the same length.
+    #[must_use]
+    pub fn new(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "All grid rows must have the same length!"
+        );
+        Self::from_data(rows.into_iter().flatten().collect(), height, width)
+    }
+
+    /// Wraps row-major storage, giving every grid without cells the shape `(0, 0)`.
+    const fn from_data(data: Vec<T>, height: usize, width: usize) -> Self {
+        if data.is_empty() {
+            return Self {
+                data,
+                height: 0,
+                width: 0,
+            };
+        }
+        Self {
+            data,
+            height,
+            width,
+        }
+    }
+
+    /// Builds a grid of `rows` × `cols` cells by calling `f` on each position in reading order.
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let data = (0..rows as u64)
+            .flat_map(|row| (0..cols as u64).map(move |col| Position::new(row, col)))
+            .map(&mut f)
+            .collect();
+        Self::from_data(data, rows, cols)
+    }
+
+    /// Builds a grid of `rows` × `cols` copies of `value`.
+    #[must_use]
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_data(vec![value; rows * cols], rows, cols)
+    }
+
+    /// Returns `true` if the grid has no cells.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Index into the row-major storage, or `None` if `position` lies outside the grid.
+    fn index(&self, Position { row, col }: &Position) -> Option<usize> {
+        let row = usize::try_from(*row).ok()?;
+        let col = usize::try_from(*col).ok()?;
+        (row < self.height && col < self.width).then(|| row * self.width + col)
+    }
+
+    /// Returns `true` if `position` lies inside the grid.
     #[must_use]
-    pub fn get(&self, Position { row, col }: &Position) -> Option<&T> {
-        self.data
-            .get(usize::try_from(*row).expect("The Advent of Code grid is not that big!"))
-            .map(|c| {
-                c.get(usize::try_from(*col).expect("The AoC puzzle grid is not that large!"))
-            })?
+    pub fn contains(&self, position: &Position) -> bool {
+        self.index(position).is_some()
     }
 
-    /// .
+    /// Takes one step from `position` in `direction`, or `None` if that would leave the grid.
+    #[must_use]
+    pub fn step(&self, position: &Position, direction: Direction) -> Option<Position> {
+        position.step(direction).filter(|next| self.contains(next))
+    }
+
+    #[must_use]
+    pub fn get(&self, position: &Position) -> Option<&T> {
+        self.index(position).map(|index| &self.data[index])
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, position: &Position) -> Option<&mut T> {
+        self.index(position).map(|index| &mut self.data[index])
+    }
+
+    /// Overwrites the value at `position`.
     ///
     /// # Panics
     ///
-    /// Panics if usize cannot be cast from input u64s
-    pub fn set(&mut self, Position { row, col }: &Position, new_val: T) {
+    /// Panics if `position` lies outside the grid.
+    pub fn set(&mut self, position: &Position, new_val: T) {
         *self
-            .data
-            .get_mut(
-                usize::try_from(*row)
-                    .expect("Usize to u64 should be fine for problems of regular AOC size"),
-            )
-            .expect("AOC grids are usually small")
-            .get_mut(
-                usize::try_from(*col)
-                    .expect("Usize from u64 is fine for small problems, like AOC usually are"),
-            )
-            .expect("AOC grids are usually small") = new_val;
+            .get_mut(position)
+            .expect("Can only set values inside the grid") = new_val;
+    }
+
+    /// Exchanges the values at `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either position lies outside the grid.
+    pub fn swap(&mut self, a: &Position, b: &Position) {
+        let a = self.index(a).expect("Can only swap values inside the grid");
+        let b = self.index(b).expect("Can only swap values inside the grid");
+        self.data.swap(a, b);
+    }
+
+    /// Returns row number `row` as a slice, or `None` if it lies outside the grid.
+    #[must_use]
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.data[row * self.width..(row + 1) * self.width])
+    }
+
+    /// Iterates over the rows of this [`Grid<T>`] as slices, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| &self.data[row * self.width..(row + 1) * self.width])
     }
 
     pub fn to_indexed_iterator(&self) -> impl Iterator<Item = (Position, &T)> {
-        self.data.iter().enumerate().flat_map(|(row, row_vector)| {
-            row_vector
-                .iter()
-                .enumerate()
-                .map(move |(col, digit)| (Position::new(row as u64, col as u64), digit))
+        let width = self.width;
+        self.data.iter().enumerate().map(move |(index, value)| {
+            (
+                Position::new((index / width) as u64, (index % width) as u64),
+                value,
+            )
         })
     }
+
+    /// Parses a grid from text, one row per line, mapping every character with `str_caster`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lines are not all of the same length.
     pub fn parse_grid<U>(value: &str, str_caster: U) -> Self
     where
         U: Fn(char) -> T,
     {
-        Self {
-            data: value
-                .lines()
-                .map(|l| l.chars().map(&str_caster).collect())
-                .collect(),
+        Self::try_parse_grid(value, |c| Some(str_caster(c))).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Parses a grid from text, one row per line, with a cell mapper that may reject characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GridParseError`] pointing at the first character `str_caster` rejects, or at
+    /// the first line whose width differs from the first line.
+    pub fn try_parse_grid<U>(value: &str, str_caster: U) -> Result<Self, GridParseError>
+    where
+        U: Fn(char) -> Option<T>,
+    {
+        let expected_width = value.lines().next().map_or(0, |l| l.chars().count());
+        let mut data = Vec::with_capacity(value.len());
+        let mut height = 0;
+        for (line_index, line) in value.lines().enumerate() {
+            let mut found_width = 0;
+            for (col_index, character) in line.chars().enumerate() {
+                data.push(str_caster(character).ok_or(GridParseError::UnexpectedChar {
+                    line: line_index + 1,
+                    column: col_index + 1,
+                    character,
+                    expected_width,
+                })?);
+                found_width += 1;
+            }
+            if found_width != expected_width {
+                return Err(GridParseError::RaggedRow {
+                    line: line_index + 1,
+                    expected_width,
+                    found_width,
+                });
+            }
+            height += 1;
         }
+        Ok(Self::from_data(data, height, expected_width))
+    }
+
+    /// Iterates over the in-bounds neighbours of `position`, with the direction each lies in.
+    pub fn neighbours(
+        &self,
+        position: &Position,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = (Direction, Position, &T)> {
+        let position = *position;
+        neighbourhood
+            .directions()
+            .iter()
+            .filter_map(move |direction| {
+                let neighbour = position.step(*direction)?;
+                self.get(&neighbour)
+                    .map(|value| (*direction, neighbour, value))
+            })
+    }
+
+    /// Iterates over the in-bounds cells sharing an edge with `position`.
+    pub fn orthogonal_neighbours(
+        &self,
+        position: &Position,
+    ) -> impl Iterator<Item = (Direction, Position, &T)> {
+        self.neighbours(position, Neighbourhood::Orthogonal)
     }
 
+    /// Iterates over the in-bounds cells at each of `offsets` from `position`.
+    pub fn stencil<'a>(
+        &'a self,
+        position: &Position,
+        offsets: &'a [Offset],
+    ) -> impl Iterator<Item = (Offset, Position, &'a T)> + 'a {
+        let position = *position;
+        offsets.iter().filter_map(move |offset| {
+            let neighbour = (position + *offset)?;
+            self.get(&neighbour)
+                .map(|value| (*offset, neighbour, value))
+        })
+    }
+
+    /// Returns the shape of this [`Grid<T>`] as a number of rows and columns.
+    ///
+    /// An empty grid has the shape `(0, 0)`.
     #[must_use]
-    pub fn get_direct_position_value_neighbours(&self, pos: &Position) -> Vec<(Position, &T)> {
-        pos.direct_neighbours()
-            .into_iter()
-            .filter_map(|pos| self.get(&pos).map(|value| (pos, value)))
-            .collect()
+    pub const fn shape(&self) -> Position {
+        Position::new(self.height as u64, self.width as u64)
+    }
+
+    /// The position of the first cell, in reading order, for which `predicate` holds.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.find_all(predicate).next()
     }
 
-    /// Returns the shape of this [`Grid<T>`].
+    /// Iterates over the positions of the cells for which `predicate` holds, in reading order.
+    pub fn find_all<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Position> + 'a {
+        self.to_indexed_iterator()
+            .filter(move |(_position, value)| predicate(value))
+            .map(|(position, _value)| position)
+    }
+
+    /// The number of cells for which `predicate` holds.
+    pub fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.data.iter().filter(|value| predicate(value)).count()
+    }
+
+    /// Builds a grid of the same shape by applying `f` to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_data(self.data.iter().map(f).collect(), self.height, self.width)
+    }
+
+    /// Like [`Grid::map`], but `f` also receives the position of each cell.
+    pub fn map_with_position<U>(&self, mut f: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        Grid::from_data(
+            self.to_indexed_iterator()
+                .map(|(position, value)| f(position, value))
+                .collect(),
+            self.height,
+            self.width,
+        )
+    }
+
+    /// Combines two grids of the same shape cell by cell.
     ///
     /// # Panics
     ///
-    /// Panics if usize can not be converted to u64 on this platform.
-    #[must_use]
-    pub fn shape(&self) -> Position {
-        Position::new(
+    /// Panics if the grids differ in shape.
+    pub fn zip_with<U, V>(&self, other: &Grid<U>, f: impl FnMut(&T, &U) -> V) -> Grid<V> {
+        assert_eq!(
+            self.shape(),
+            other.shape(),
+            "Only grids of the same shape can be zipped"
+        );
+        let mut f = f;
+        Grid::from_data(
             self.data
-                .len()
-                .try_into()
-                .expect("The AOC grids are small!"),
-            self.data
-                .first()
-                .expect("First grid vector should not be empty!")
-                .len()
-                .try_into()
-                .expect("The AOC grids are small!"),
+                .iter()
+                .zip(&other.data)
+                .map(|(a, b)| f(a, b))
+                .collect(),
+            self.height,
+            self.width,
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn grid_flat_storage_access() {
+        let mut grid = Grid::parse_grid("abc\ndef", |c| c);
+        assert_eq!(grid.shape(), Position::new(2, 3));
+        assert_eq!(grid.get(&Position::new(1, 2)), Some(&'f'));
+        assert_eq!(grid.get(&Position::new(0, 3)), None);
+        assert_eq!(grid.get(&Position::new(2, 0)), None);
+        assert_eq!(grid.get(&Position::new(u64::MAX, u64::MAX)), None);
+        grid.set(&Position::new(0, 1), 'x');
+        assert_eq!(grid.row(0), Some(&['a', 'x', 'c'][..]));
+        grid.swap(&Position::new(0, 0), &Position::new(1, 0));
+        assert_eq!(grid.get(&Position::new(0, 0)), Some(&'d'));
+        grid.swap(&Position::new(0, 0), &Position::new(1, 0));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'x', 'c'][..], &['d', 'e', 'f'][..]]
+        );
+    }
+
+    #[test]
+    fn grid_try_parse_diagnostics() {
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(
+            Grid::try_parse_grid("12\n34\n", digit),
+            Ok(Grid::new(vec![vec![1, 2], vec![3, 4]]))
+        );
+        assert_eq!(
+            Grid::try_parse_grid("12\n3x", digit),
+            Err(GridParseError::UnexpectedChar {
+                line: 2,
+                column: 2,
+                character: 'x',
+                expected_width: 2
+            })
+        );
+        assert_eq!(
+            Grid::try_parse_grid("123\n45", digit),
+            Err(GridParseError::RaggedRow {
+                line: 2,
+                expected_width: 3,
+                found_width: 2
+            })
+        );
+    }
+
+    #[test]
+    fn grid_neighbourhoods() {
+        let grid = Grid::parse_grid("abc\ndef\nghi", |c| c);
+        let corner: Vec<_> = grid.orthogonal_neighbours(&Position::new(0, 0)).collect();
+        assert_eq!(
+            corner,
+            vec![
+                (Direction::Right, Position::new(0, 1), &'b'),
+                (Direction::Down, Position::new(1, 0), &'d')
+            ]
+        );
+        let centre = Position::new(1, 1);
+        assert_eq!(
+            grid.neighbours(&centre, Neighbourhood::Diagonal)
+                .map(|(_, _, c)| *c)
+                .collect::<String>(),
+            "ciga"
+        );
+        assert_eq!(grid.neighbours(&centre, Neighbourhood::King).count(), 8);
+        assert_eq!(
+            grid.neighbours(&Position::new(2, 2), Neighbourhood::King)
+                .map(|(_, _, c)| *c)
+                .collect::<String>(),
+            "fhe"
+        );
+        let knight = [Offset::new(-2, 1), Offset::new(1, 2), Offset::new(2, 1)];
+        assert_eq!(
+            grid.stencil(&Position::new(0, 0), &knight)
+                .map(|(offset, pos, c)| (offset, pos, *c))
+                .collect::<Vec<_>>(),
+            vec![
+                (Offset::new(1, 2), Position::new(1, 2), 'f'),
+                (Offset::new(2, 1), Position::new(2, 1), 'h')
+            ]
+        );
+        assert_eq!(
+            Position::new(0, 0).neighbours(Neighbourhood::King).count(),
+            3
+        );
+        assert_eq!(Position::new(0, 3).orthogonal_neighbours().count(), 3);
+    }
+
+    #[test]
+    fn grid_queries_and_maps() {
+        let grid = Grid::parse_grid("1.2\n.3.", |c| c.to_digit(10));
+        assert_eq!(grid.find(Option::is_some), Some(Position::new(0, 0)));
+        assert_eq!(
+            grid.find(|cell| *cell == Some(3)),
+            Some(Position::new(1, 1))
+        );
+        assert_eq!(grid.find(|cell| *cell == Some(4)), None);
+        assert_eq!(
+            grid.find_all(Option::is_none).collect::<Vec<_>>(),
+            [(0, 1), (1, 0), (1, 2)].map(|(row, col)| Position::new(row, col))
+        );
+        assert_eq!(grid.count(Option::is_some), 3);
+
+        let digits = grid.map(|cell| cell.unwrap_or(0));
+        assert_eq!(digits, Grid::new(vec![vec![1, 0, 2], vec![0, 3, 0]]));
+        assert_eq!(
+            digits.map_with_position(|position, digit| position.col + u64::from(*digit)),
+            Grid::new(vec![vec![1, 1, 4], vec![0, 4, 2]])
+        );
+        let ramp = Grid::from_fn(2, 3, |position| position.row * 3 + position.col);
+        assert_eq!(
+            ramp.zip_with(&digits, |a, b| a * u64::from(*b)),
+            Grid::new(vec![vec![0, 0, 4], vec![0, 12, 0]])
+        );
+        assert_eq!(Grid::filled(2, 2, 'x'), Grid::parse_grid("xx\nxx", |c| c));
+    }
+
+    #[test]
+    fn grid_empty() {
+        let empty: Grid<char> = Grid::parse_grid("", |c| c);
+        assert!(empty.is_empty());
+        assert_eq!(empty.shape(), Position::new(0, 0));
+        assert_eq!(Grid::<char>::new(vec![vec![], vec![]]), empty);
+        assert_eq!(Grid::filled(3, 0, 'x'), empty);
+        assert_eq!(Grid::from_fn(0, 4, |_| 'x'), empty);
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.to_indexed_iterator().count(), 0);
+        assert_eq!(empty.find(|_| true), None);
+        assert_eq!(empty.map(|c| *c), empty);
+    }
+}
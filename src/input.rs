@@ -0,0 +1,176 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The environment variable naming a directory of `NN.txt` puzzle inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where inputs are looked for when nothing else is given: the crate's `data` directory, so it
+/// is found whichever directory `aoc` is run from.
+pub const DEFAULT_INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+/// A place a puzzle input can be read from.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "standard input"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// None of the places tried held an input for the day.
+    Missing { day: u8, tried: Vec<InputSource> },
+    /// An input was there but could not be read.
+    Unreadable {
+        source: InputSource,
+        error: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { day, tried } => {
+                write!(f, "No input found for day {day:02}, tried:")?;
+                tried
+                    .iter()
+                    .try_for_each(|source| write!(f, "\n    {source}"))
+            }
+            Self::Unreadable { source, error } => write!(f, "Couldn't read {source}: {error}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Missing { .. } => None,
+            Self::Unreadable { error, .. } => Some(error),
+        }
+    }
+}
+
+/// The places to look for `day`'s input, in order.
+///
+/// An `explicit` path is the only place looked at, with `-` standing for standard input.
+/// Otherwise `NN.txt` is looked for in `input_dir`, then in [`DEFAULT_INPUT_DIR`].
+#[must_use]
+pub fn input_sources(
+    day: u8,
+    explicit: Option<&Path>,
+    input_dir: Option<&Path>,
+) -> Vec<InputSource> {
+    if let Some(path) = explicit {
+        return vec![if path == Path::new("-") {
+            InputSource::Stdin
+        } else {
+            InputSource::File(path.to_owned())
+        }];
+    }
+    let file_name = format!("{day:02}.txt");
+    input_dir
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .into_iter()
+        .chain([Path::new(DEFAULT_INPUT_DIR)])
+        .map(|dir| InputSource::File(dir.join(&file_name)))
+        .collect()
+}
+
+/// Reads the first of `sources` that exists.
+///
+/// # Errors
+///
+/// Returns [`InputError::Missing`] naming every source if none of them exist, or
+/// [`InputError::Unreadable`] if one exists but cannot be read.
+pub fn read_first(day: u8, sources: Vec<InputSource>) -> Result<String, InputError> {
+    for source in &sources {
+        let read = match source {
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text).map(|_| text)
+            }
+            InputSource::File(path) => std::fs::read_to_string(path),
+        };
+        match read {
+            Ok(text) => return Ok(text),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => {
+                return Err(InputError::Unreadable {
+                    source: source.clone(),
+                    error,
+                })
+            }
+        }
+    }
+    Err(InputError::Missing {
+        day,
+        tried: sources,
+    })
+}
+
+/// Reads `day`'s input from the `explicit` path if given, or else from the directory named by
+/// [`INPUT_DIR_VAR`] or the default one.
+///
+/// # Errors
+///
+/// Returns an [`InputError`] if no input could be read, see [`read_first`].
+pub fn read_input(day: u8, explicit: Option<&Path>) -> Result<String, InputError> {
+    let input_dir = std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    read_first(day, input_sources(day, explicit, input_dir.as_deref()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn input_sources_in_order() {
+        let data = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        assert_eq!(
+            input_sources(6, None, Some(Path::new("inputs"))),
+            [
+                InputSource::File(PathBuf::from("inputs/06.txt")),
+                InputSource::File(data.join("06.txt")),
+            ]
+        );
+        assert_eq!(
+            input_sources(12, None, Some(Path::new(""))),
+            [InputSource::File(data.join("12.txt"))]
+        );
+        assert_eq!(
+            input_sources(6, Some(Path::new("example.txt")), Some(Path::new("inputs"))),
+            [InputSource::File(PathBuf::from("example.txt"))]
+        );
+        assert_eq!(
+            input_sources(6, Some(Path::new("-")), None),
+            [InputSource::Stdin]
+        );
+    }
+
+    #[test]
+    fn read_first_existing_input() {
+        let dir = std::env::temp_dir().join(format!("aoc2024-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("The temporary directory is writable");
+        let present = dir.join("03.txt");
+        std::fs::write(&present, "mul(2,3)").expect("The temporary directory is writable");
+        let absent = InputSource::File(dir.join("missing.txt"));
+
+        let text = read_first(3, vec![absent.clone(), InputSource::File(present)]);
+        assert_eq!(text.ok().as_deref(), Some("mul(2,3)"));
+
+        let missing = read_first(3, vec![absent]).expect_err("Nothing to read");
+        assert!(missing.to_string().contains("missing.txt"));
+        assert!(missing.to_string().starts_with("No input found for day 03"));
+        std::fs::remove_dir_all(&dir).expect("The temporary directory can be removed");
+    }
+}
@@ -0,0 +1,229 @@
+use crate::grid::Grid;
+use crate::position::Position;
+
+/// A single overwritten cell.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Change<T> {
+    pub position: Position,
+    pub old: T,
+    pub new: T,
+}
+
+/// A grid that records every change made through it, grouped into steps that can be undone,
+/// redone and replayed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Journal<T> {
+    grid: Grid<T>,
+    steps: Vec<Vec<Change<T>>>,
+    /// The changes of the step still being recorded.
+    open: Vec<Change<T>>,
+    /// The number of steps currently applied to `grid`; later ones can be redone.
+    applied: usize,
+}
+
+impl<T> Journal<T> {
+    /// Starts recording changes to `grid`, with no steps taken yet.
+    #[must_use]
+    pub const fn new(grid: Grid<T>) -> Self {
+        Self {
+            grid,
+            steps: Vec::new(),
+            open: Vec::new(),
+            applied: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    #[must_use]
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// The number of steps currently applied, not counting the open one.
+    #[must_use]
+    pub const fn current_step(&self) -> usize {
+        self.applied
+    }
+
+    /// The number of steps recorded, including any that were undone and can be redone.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// The changes made by each recorded step, in order.
+    #[must_use]
+    pub fn diffs(&self) -> &[Vec<Change<T>>] {
+        &self.steps
+    }
+}
+
+impl<T: Clone> Journal<T> {
+    /// Overwrites the value at `position`, recording the change in the open step.
+    ///
+    /// Steps undone before this can no longer be redone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` lies outside the grid.
+    pub fn set(&mut self, position: &Position, value: T) {
+        self.steps.truncate(self.applied);
+        let cell = self
+            .grid
+            .get_mut(position)
+            .expect("Can only set values inside the grid");
+        let old = std::mem::replace(cell, value.clone());
+        self.open.push(Change {
+            position: *position,
+            old,
+            new: value,
+        });
+    }
+
+    /// Closes the open step, even if it made no changes, so that step numbers can follow the
+    /// ticks of a simulation.
+    pub fn end_step(&mut self) {
+        self.steps.truncate(self.applied);
+        self.steps.push(std::mem::take(&mut self.open));
+        self.applied = self.steps.len();
+    }
+
+    /// Reverts the last applied step, returning `false` if there was none.
+    ///
+    /// An open step with changes in it is closed first.
+    pub fn undo(&mut self) -> bool {
+        if !self.open.is_empty() {
+            self.end_step();
+        }
+        let Some(step) = self.applied.checked_sub(1) else {
+            return false;
+        };
+        for change in self.steps[step].iter().rev() {
+            self.grid.set(&change.position, change.old.clone());
+        }
+        self.applied = step;
+        true
+    }
+
+    /// Reapplies the next undone step, returning `false` if there was none.
+    pub fn redo(&mut self) -> bool {
+        let Some(changes) = self.steps.get(self.applied) else {
+            return false;
+        };
+        for change in changes {
+            self.grid.set(&change.position, change.new.clone());
+        }
+        self.applied += 1;
+        true
+    }
+
+    /// Undoes or redoes steps until exactly `step` of them are applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `step` steps have been recorded.
+    pub fn jump_to(&mut self, step: usize) {
+        if !self.open.is_empty() {
+            self.end_step();
+        }
+        assert!(
+            step <= self.steps.len(),
+            "Cannot jump to step {step}, only {} were recorded",
+            self.steps.len()
+        );
+        while self.applied > step {
+            self.undo();
+        }
+        while self.applied < step {
+            self.redo();
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Journal<T> {
+    /// Runs `edit` on the grid as one step, recording whichever cells it changed.
+    ///
+    /// Changes still open from [`Journal::set`] are ended as a step of their own first. This
+    /// compares the whole grid before and after, so any grid operation can be journaled.
+    pub fn record<R>(&mut self, edit: impl FnOnce(&mut Grid<T>) -> R) -> R {
+        if !self.open.is_empty() {
+            self.end_step();
+        }
+        let before = self.grid.clone();
+        let result = edit(&mut self.grid);
+        let changes: Vec<Change<T>> = before
+            .to_indexed_iterator()
+            .zip(self.grid.to_indexed_iterator())
+            .filter(|((_position, old), (_, new))| old != new)
+            .map(|((position, old), (_, new))| Change {
+                position,
+                old: old.clone(),
+                new: new.clone(),
+            })
+            .collect();
+        self.steps.truncate(self.applied);
+        self.open.extend(changes);
+        self.end_step();
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn journal_undo_redo_and_jump() {
+        let mut journal = Journal::new(Grid::parse_grid("abc\ndef", |c| c));
+        let original = journal.grid().clone();
+        journal.set(&Position::new(0, 0), 'x');
+        journal.set(&Position::new(0, 0), 'y');
+        journal.end_step();
+        journal.end_step();
+        journal.record(|grid| grid.swap(&Position::new(1, 0), &Position::new(1, 2)));
+        assert_eq!(journal.len(), 3);
+        assert_eq!(journal.grid(), &Grid::parse_grid("ybc\nfed", |c| c));
+        assert_eq!(journal.diffs()[0].len(), 2);
+        assert!(journal.diffs()[1].is_empty());
+        assert_eq!(
+            journal.diffs()[2][0],
+            Change {
+                position: Position::new(1, 0),
+                old: 'd',
+                new: 'f'
+            }
+        );
+
+        assert!(journal.undo());
+        assert_eq!(journal.current_step(), 2);
+        journal.jump_to(0);
+        assert_eq!(journal.grid(), &original);
+        assert!(!journal.undo());
+        assert!(journal.redo());
+        assert_eq!(journal.grid().get(&Position::new(0, 0)), Some(&'y'));
+
+        journal.set(&Position::new(0, 1), 'z');
+        journal.end_step();
+        assert_eq!(journal.len(), 2);
+        assert!(!journal.redo());
+    }
+
+    #[test]
+    fn journal_record_keeps_earlier_sets_apart() {
+        let mut journal = Journal::new(Grid::parse_grid("ab", |c| c));
+        journal.set(&Position::new(0, 0), 'x');
+        journal.record(|grid| grid.set(&Position::new(0, 1), 'y'));
+        assert_eq!(journal.len(), 2);
+        assert!(journal.undo());
+        assert_eq!(journal.grid(), &Grid::parse_grid("xb", |c| c));
+    }
+}
@@ -1,2 +1,21 @@
+pub mod bit_grid;
+pub mod components;
+pub mod dag;
+pub mod days;
+pub mod direction;
+pub mod glyph;
 pub mod grid;
+pub mod input;
+pub mod journal;
+pub mod lines;
+pub mod offset;
+pub mod pattern;
 pub mod position;
+pub mod push;
+pub mod region;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
+pub mod torus;
+pub mod transform;
+pub mod window;
@@ -0,0 +1,200 @@
+use crate::grid::Grid;
+use crate::offset::Offset;
+use crate::position::Position;
+
+/// A straight run of cells through a [`Grid<T>`], yielding each position with its value.
+#[derive(Debug)]
+pub struct Line<'a, T> {
+    grid: &'a Grid<T>,
+    next: Position,
+    step: Offset,
+    remaining: u64,
+}
+
+impl<T> Clone for Line<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T> Line<'a, T> {
+    /// The line from `start` that keeps taking `step` until it would leave the grid.
+    fn to_edge(grid: &'a Grid<T>, start: Position, step: Offset) -> Self {
+        let Position { row, col } = grid.shape();
+        let steps_within = |from: u64, size: u64, delta: i64| match delta {
+            0 => u64::MAX,
+            d if d > 0 => (size - 1 - from) / d.unsigned_abs() + 1,
+            d => from / d.unsigned_abs() + 1,
+        };
+        let remaining = if grid.contains(&start) {
+            steps_within(start.row, row, step.row).min(steps_within(start.col, col, step.col))
+        } else {
+            0
+        };
+        Self {
+            grid,
+            next: start,
+            step,
+            remaining,
+        }
+    }
+
+    fn at(&self, steps: u64) -> (Position, &'a T) {
+        let steps = i64::try_from(steps).expect("Lines are shorter than the grid");
+        let position = (self.next + self.step * steps).expect("Lines stay inside the grid");
+        (
+            position,
+            self.grid
+                .get(&position)
+                .expect("Lines stay inside the grid"),
+        )
+    }
+}
+
+impl<'a, T> Iterator for Line<'a, T> {
+    type Item = (Position, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = self.at(0);
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.next = self.at(1).0;
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.remaining).unwrap_or(usize::MAX);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Line<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.at(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Line<'_, T> {}
+
+impl<T> Grid<T> {
+    /// Iterates over the columns, left to right, each read top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = Line<'_, T>> {
+        (0..self.shape().col)
+            .map(|col| Line::to_edge(self, Position::new(0, col), Offset::new(1, 0)))
+    }
+
+    /// Iterates over the diagonals running down and to the right.
+    ///
+    /// The first diagonal is the top-right corner and the last is the bottom-left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        let Position { row, col } = self.shape();
+        let top_row = (0..col).rev().map(|col| Position::new(0, col));
+        let left_column = (1..row).map(|row| Position::new(row, 0));
+        top_row
+            .chain(left_column)
+            .map(|start| Line::to_edge(self, start, Offset::new(1, 1)))
+    }
+
+    /// Iterates over the anti-diagonals running up and to the right.
+    ///
+    /// The first anti-diagonal is the top-left corner and the last is the bottom-right corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        let Position { row, col } = self.shape();
+        let left_column = (0..row).map(|row| Position::new(row, 0));
+        let bottom_row = (1..col).map(move |col| Position::new(row - 1, col));
+        left_column
+            .chain(bottom_row)
+            .map(|start| Line::to_edge(self, start, Offset::new(-1, 1)))
+    }
+
+    /// Walks from `from`, inclusive, by repeated `step`s until the edge of the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` does not move at all.
+    pub fn ray(&self, from: &Position, step: impl Into<Offset>) -> Line<'_, T> {
+        let step = step.into();
+        assert_ne!(step, Offset::ZERO, "A ray has to go somewhere");
+        Line::to_edge(self, *from, step)
+    }
+
+    /// Walks like [`Grid::ray`], but stops after the first cell for which `is_end` holds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` does not move at all.
+    pub fn ray_until<'a>(
+        &'a self,
+        from: &Position,
+        step: impl Into<Offset>,
+        is_end: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (Position, &'a T)> + 'a {
+        let mut stopped = false;
+        self.ray(from, step).take_while(move |(_pos, value)| {
+            let keep = !stopped;
+            stopped = stopped || is_end(value);
+            keep
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::direction::Direction;
+
+    fn text(line: Line<'_, char>) -> String {
+        line.map(|(_pos, c)| *c).collect()
+    }
+
+    #[test]
+    fn grid_lines() {
+        let grid = Grid::parse_grid("abc\ndef", |c| c);
+        assert_eq!(
+            grid.columns().map(text).collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals().map(text).collect::<Vec<_>>(),
+            ["c", "bf", "ae", "d"]
+        );
+        assert_eq!(
+            grid.anti_diagonals().map(text).collect::<Vec<_>>(),
+            ["a", "db", "ec", "f"]
+        );
+        assert_eq!(
+            grid.diagonals()
+                .map(|line| line.rev().map(|(_pos, c)| *c).collect::<String>())
+                .collect::<Vec<_>>(),
+            ["c", "fb", "ea", "d"]
+        );
+    }
+
+    #[test]
+    fn grid_rays() {
+        let grid = Grid::parse_grid("abcd\nefgh\nijkl", |c| c);
+        let start = Position::new(1, 1);
+        assert_eq!(text(grid.ray(&start, Direction::Right)), "fgh");
+        assert_eq!(text(grid.ray(&start, Direction::UpLeft)), "fa");
+        assert_eq!(
+            text(grid.ray(&Position::new(0, 0), Offset::new(1, 2))),
+            "ag"
+        );
+        assert_eq!(grid.ray(&start, Direction::Down).len(), 2);
+        assert_eq!(text(grid.ray(&Position::new(5, 5), Direction::Up)), "");
+        assert_eq!(
+            grid.ray_until(&Position::new(0, 0), Direction::Right, |c| *c == 'c')
+                .map(|(pos, _c)| pos)
+                .collect::<Vec<_>>(),
+            [0, 1, 2].map(|col| Position::new(0, col))
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A signed displacement on the grid, such as the step between two [`Position`]s.
+///
+/// [`Position`]: crate::position::Position
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Default)]
+pub struct Offset {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Offset {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    #[must_use]
+    pub const fn new(row: i64, col: i64) -> Self {
+        Self { row, col }
+    }
+
+    /// The number of orthogonal steps needed to cover this offset.
+    #[must_use]
+    pub const fn manhattan(self) -> u64 {
+        self.row.unsigned_abs() + self.col.unsigned_abs()
+    }
+}
+
+impl Add for Offset {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Sub for Offset {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Neg for Offset {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<i64> for Offset {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        Self::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position::Position;
+
+    #[test]
+    fn offset_algebra() {
+        let a = Offset::new(2, -3);
+        let b = Offset::new(-1, 1);
+        assert_eq!(a + b, Offset::new(1, -2));
+        assert_eq!(a - b, Offset::new(3, -4));
+        assert_eq!(-a, Offset::new(-2, 3));
+        assert_eq!(a * 2, Offset::new(4, -6));
+        assert_eq!(a + -a, Offset::ZERO);
+        assert_eq!(a.manhattan(), 5);
+    }
+
+    #[test]
+    fn position_offset_arithmetic() {
+        let pos = Position::new(1, 5);
+        assert_eq!(pos + Offset::new(2, -5), Some(Position::new(3, 0)));
+        assert_eq!(pos + Offset::new(-2, 0), None);
+        assert_eq!(pos - Offset::new(1, 1), Some(Position::new(0, 4)));
+        assert_eq!(Position::new(0, 2) - pos, Offset::new(-1, -3));
+        assert_eq!(pos + (Position::new(4, 4) - pos), Some(Position::new(4, 4)));
+    }
+}
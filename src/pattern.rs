@@ -0,0 +1,152 @@
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::transform::Symmetry;
+
+/// A small grid of cells to look for in a larger [`Grid<T>`], where `None` cells match anything.
+///
+/// A pattern may be searched for under several [`Symmetry`]s at once; symmetries that map the
+/// pattern onto an arrangement already being searched for are skipped, so each placement is only
+/// reported once.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Pattern<T> {
+    cells: Grid<Option<T>>,
+    symmetries: Vec<Symmetry>,
+}
+
+/// Where a [`Pattern`] was found, and under which of its symmetries.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Match {
+    /// The top-left corner of the transformed pattern in the searched grid.
+    pub position: Position,
+    pub symmetry: Symmetry,
+}
+
+impl<T> Pattern<T> {
+    /// A pattern matched only as given.
+    #[must_use]
+    pub fn new(cells: Grid<Option<T>>) -> Self {
+        Self {
+            cells,
+            symmetries: vec![Symmetry::Identity],
+        }
+    }
+
+    /// Parses a pattern one row per line; characters `str_caster` maps to `None` are wildcards.
+    pub fn parse<U>(value: &str, str_caster: U) -> Self
+    where
+        U: Fn(char) -> Option<T>,
+    {
+        Self::new(Grid::parse_grid(value, str_caster))
+    }
+
+    /// Searches for the pattern as given under each of `symmetries` instead, skipping those that
+    /// look the same as an earlier one.
+    #[must_use]
+    pub fn under(mut self, symmetries: &[Symmetry]) -> Self
+    where
+        T: PartialEq,
+    {
+        let cells = &self.cells;
+        let looks_same = |a: Symmetry, b: Symmetry| {
+            let (a, b) = (cells.view(a), cells.view(b));
+            a.shape() == b.shape()
+                && a.to_indexed_iterator()
+                    .all(|(position, cell)| b.get(&position) == Some(cell))
+        };
+        let mut distinct: Vec<Symmetry> = Vec::new();
+        for &symmetry in symmetries {
+            if !distinct.iter().any(|&known| looks_same(known, symmetry)) {
+                distinct.push(symmetry);
+            }
+        }
+        self.symmetries = distinct;
+        self
+    }
+
+    /// Searches for the pattern under every rotation and reflection.
+    #[must_use]
+    pub fn under_all(self) -> Self
+    where
+        T: PartialEq,
+    {
+        self.under(&Symmetry::ALL)
+    }
+
+    /// The distinct symmetries this pattern is searched for under.
+    pub fn symmetries(&self) -> impl Iterator<Item = Symmetry> + '_ {
+        self.symmetries.iter().copied()
+    }
+
+    /// Every placement of the pattern in `grid`, grouped by symmetry in the order they were given.
+    pub fn find_all<'a>(&'a self, grid: &'a Grid<T>) -> impl Iterator<Item = Match> + 'a
+    where
+        T: PartialEq,
+    {
+        self.symmetries.iter().flat_map(move |&symmetry| {
+            let view = self.cells.view(symmetry);
+            let (height, width) = if symmetry.swaps_axes() {
+                (self.cells.width(), self.cells.height())
+            } else {
+                (self.cells.height(), self.cells.width())
+            };
+            grid.windows(height, width)
+                .filter(move |window| {
+                    view.to_indexed_iterator().all(|(local, cell)| {
+                        cell.as_ref()
+                            .is_none_or(|wanted| window.get(&local) == Some(wanted))
+                    })
+                })
+                .map(move |window| Match {
+                    position: window.origin(),
+                    symmetry,
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn letters(c: char) -> Option<char> {
+        (c != '.').then_some(c)
+    }
+
+    #[test]
+    fn pattern_symmetries_are_distinct() {
+        let word = Pattern::parse("AB", letters).under_all();
+        assert_eq!(
+            word.symmetries().collect::<Vec<_>>(),
+            [
+                Symmetry::Identity,
+                Symmetry::Rotate90,
+                Symmetry::Rotate180,
+                Symmetry::Rotate270
+            ]
+        );
+        let square = Pattern::parse("A.\n.A", letters).under_all();
+        assert_eq!(square.symmetries().count(), 2);
+    }
+
+    #[test]
+    fn pattern_find_all() {
+        let grid = Grid::parse_grid("ABA\nxBx\nABA", |c| c);
+        let corner = Pattern::parse("A.\n.B", letters);
+        assert_eq!(
+            corner.find_all(&grid).collect::<Vec<_>>(),
+            [Match {
+                position: Position::new(0, 0),
+                symmetry: Symmetry::Identity
+            }]
+        );
+        let corners = corner.under_all();
+        assert_eq!(corners.find_all(&grid).count(), 4);
+        assert!(corners.find_all(&grid).any(|found| found
+            == Match {
+                position: Position::new(1, 1),
+                symmetry: Symmetry::Rotate180
+            }));
+        let vertical = Pattern::parse("A\n.\nA", letters);
+        assert_eq!(vertical.find_all(&grid).count(), 2);
+    }
+}
@@ -1,3 +1,9 @@
+use std::num::TryFromIntError;
+use std::ops::{Add, Neg, Sub};
+
+use crate::direction::{Direction, Neighbourhood};
+use crate::offset::Offset;
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct Position {
     pub row: u64,
@@ -10,17 +16,98 @@ impl Position {
         Self { row, col }
     }
 
+    /// Takes one step in `direction`, or `None` if that would leave the non-negative quadrant.
+    #[must_use]
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        self + direction.offset()
+    }
+
+    /// Returns `true` if this position lies inside a grid of the given `shape`.
     #[must_use]
-    pub fn direct_neighbours(&self) -> Vec<Self> {
-        let min_row = if self.row == 0 { 0 } else { self.row - 1 };
-        let min_col = if self.col == 0 { 0 } else { self.col - 1 };
-        let mut res: Vec<Self> = Vec::new();
-        for row in min_row..=self.row + 1 {
-            res.push(Self::new(row, self.col));
-        }
-        for col in min_col..=self.col + 1 {
-            res.push(Self::new(self.row, col));
-        }
-        res
+    pub const fn is_within(&self, shape: &Self) -> bool {
+        self.row < shape.row && self.col < shape.col
+    }
+
+    /// Iterates over the neighbours of this position, skipping any with negative coordinates.
+    pub fn neighbours(self, neighbourhood: Neighbourhood) -> impl Iterator<Item = Self> {
+        neighbourhood
+            .directions()
+            .iter()
+            .filter_map(move |direction| self.step(*direction))
+    }
+
+    /// Iterates over the up to four positions sharing an edge with this one.
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item = Self> {
+        self.neighbours(Neighbourhood::Orthogonal)
+    }
+
+    /// Iterates over this position moved by each of `offsets`, skipping negative coordinates.
+    pub fn stencil(self, offsets: &[Offset]) -> impl Iterator<Item = Self> + '_ {
+        offsets.iter().filter_map(move |offset| self + *offset)
+    }
+}
+
+/// Moves a position by an offset, or `None` if that would leave the non-negative quadrant.
+impl Add<Offset> for Position {
+    type Output = Option<Self>;
+
+    fn add(self, offset: Offset) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_add_signed(offset.row)?,
+            self.col.checked_add_signed(offset.col)?,
+        ))
+    }
+}
+
+impl Sub<Offset> for Position {
+    type Output = Option<Self>;
+
+    fn sub(self, offset: Offset) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_add_signed(offset.row.checked_neg()?)?,
+            self.col.checked_add_signed(offset.col.checked_neg()?)?,
+        ))
+    }
+}
+
+/// The offset leading from `rhs` to `self`.
+impl Sub for Position {
+    type Output = Offset;
+
+    fn sub(self, rhs: Self) -> Offset {
+        Offset::new(
+            signed_difference(self.row, rhs.row),
+            signed_difference(self.col, rhs.col),
+        )
+    }
+}
+
+/// Reads a signed offset from the origin as a position, failing for negative coordinates.
+impl TryFrom<Offset> for Position {
+    type Error = TryFromIntError;
+
+    fn try_from(offset: Offset) -> Result<Self, Self::Error> {
+        Ok(Self::new(offset.row.try_into()?, offset.col.try_into()?))
+    }
+}
+
+/// The signed offset of a position from the origin.
+impl TryFrom<Position> for Offset {
+    type Error = TryFromIntError;
+
+    fn try_from(position: Position) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            position.row.try_into()?,
+            position.col.try_into()?,
+        ))
+    }
+}
+
+fn signed_difference(lhs: u64, rhs: u64) -> i64 {
+    if lhs >= rhs {
+        i64::try_from(lhs - rhs)
+    } else {
+        i64::try_from(rhs - lhs).map(i64::neg)
     }
+    .expect("AOC coordinates are nowhere near that far apart")
 }
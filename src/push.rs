@@ -0,0 +1,136 @@
+use std::cmp::Reverse;
+use std::collections::{HashSet, VecDeque};
+
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::offset::Offset;
+use crate::position::Position;
+
+impl<T> Grid<T> {
+    /// Moves the cell at `start` one step in `direction`, shoving along every pushable cell in
+    /// the way.
+    ///
+    /// Returns the positions moved out of or into, in reading order, or `None` if a blocked cell
+    /// or the edge of the grid stopped the push, in which case nothing moves. The cells left
+    /// behind take the values of the free cells moved into.
+    pub fn push(
+        &mut self,
+        start: &Position,
+        direction: Direction,
+        is_pushable: impl Fn(&T) -> bool,
+        is_blocked: impl Fn(&T) -> bool,
+    ) -> Option<Vec<Position>> {
+        self.push_linked(start, direction, is_pushable, is_blocked, |_| [])
+    }
+
+    /// Like [`Grid::push`], for objects spanning several cells: `linked` gives the offsets from a
+    /// pushable cell to the other cells of the same object, which are pushed along with it.
+    pub fn push_linked<I>(
+        &mut self,
+        start: &Position,
+        direction: Direction,
+        is_pushable: impl Fn(&T) -> bool,
+        is_blocked: impl Fn(&T) -> bool,
+        linked: impl Fn(&T) -> I,
+    ) -> Option<Vec<Position>>
+    where
+        I: IntoIterator<Item = Offset>,
+    {
+        let mut moving = HashSet::from([*start]);
+        let mut queue = VecDeque::from([*start]);
+        while let Some(position) = queue.pop_front() {
+            let next = self.step(&position, direction)?;
+            let value = self.get(&next)?;
+            if is_blocked(value) {
+                return None;
+            }
+            if !is_pushable(value) {
+                continue;
+            }
+            let parts = linked(value)
+                .into_iter()
+                .filter_map(|offset| next + offset)
+                .chain([next]);
+            for part in parts {
+                if moving.insert(part) {
+                    queue.push_back(part);
+                }
+            }
+        }
+
+        // Move the cells furthest ahead first, so each one swaps with a cell already vacated.
+        let Offset { row, col } = direction.offset();
+        let ahead = |position: &Position| {
+            i128::from(position.row) * i128::from(row) + i128::from(position.col) * i128::from(col)
+        };
+        let mut order: Vec<Position> = moving.iter().copied().collect();
+        order.sort_by_key(|position| Reverse(ahead(position)));
+        let mut changed = HashSet::new();
+        for position in order {
+            let next = self.step(&position, direction)?;
+            self.swap(&position, &next);
+            changed.insert(position);
+            changed.insert(next);
+        }
+        let mut changed: Vec<Position> = changed.into_iter().collect();
+        changed.sort_by_key(|position| (position.row, position.col));
+        Some(changed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn push_right(text: &str) -> (Option<usize>, String) {
+        let mut grid = Grid::parse_grid(text, |c| c);
+        let start = grid.find(|c| *c == '@').expect("Tests place a robot");
+        let changed = grid.push(&start, Direction::Right, |c| *c == 'O', |c| *c == '#');
+        (
+            changed.map(|cells| cells.len()),
+            grid.rows().flatten().collect(),
+        )
+    }
+
+    #[test]
+    fn grid_push_single_cells() {
+        assert_eq!(push_right("@OO.O#"), (Some(4), ".@OOO#".to_owned()));
+        assert_eq!(push_right("@OOOO#"), (None, "@OOOO#".to_owned()));
+        assert_eq!(push_right("@OOOO"), (None, "@OOOO".to_owned()));
+        assert_eq!(push_right("@.O"), (Some(2), ".@O".to_owned()));
+    }
+
+    #[test]
+    fn grid_push_wide_objects() {
+        let linked = |c: &char| match c {
+            '[' => vec![Offset::new(0, 1)],
+            ']' => vec![Offset::new(0, -1)],
+            _ => vec![],
+        };
+        let mut grid = Grid::parse_grid("......\n.[][].\n..[]..\n..@...", |c| c);
+        let changed = grid.push_linked(
+            &Position::new(3, 2),
+            Direction::Up,
+            |c| "[]".contains(*c),
+            |c| *c == '#',
+            linked,
+        );
+        assert_eq!(changed.map(|cells| cells.len()), Some(11));
+        assert_eq!(
+            grid,
+            Grid::parse_grid(".[][].\n..[]..\n..@...\n......", |c| c)
+        );
+        let stuck = grid.clone();
+        assert_eq!(
+            grid.push_linked(
+                &Position::new(2, 2),
+                Direction::Up,
+                |c| "[]".contains(*c),
+                |c| *c == '#',
+                linked
+            ),
+            None
+        );
+        assert_eq!(grid, stuck);
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::components::Components;
+use crate::direction::Direction;
+use crate::position::Position;
+
+/// A set of grid cells, measured as a shape in the plane.
+///
+/// Each cell at `(row, col)` covers the unit square between the lattice corners `(row, col)`
+/// and `(row + 1, col + 1)`, so corners range one past the last row and column.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Region {
+    cells: HashSet<Position>,
+}
+
+/// A unit length of boundary from one corner to the next, walked with the region on its right.
+type Edge = (Position, Position, Direction);
+
+impl Region {
+    #[must_use]
+    pub fn new(cells: impl IntoIterator<Item = Position>) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, position: &Position) -> bool {
+        self.cells.contains(position)
+    }
+
+    /// The number of cells.
+    #[must_use]
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cell sides facing a cell outside the region, holes included.
+    #[must_use]
+    pub fn perimeter(&self) -> usize {
+        self.edges().len()
+    }
+
+    /// The number of straight sides over every boundary, holes included.
+    #[must_use]
+    pub fn sides(&self) -> usize {
+        self.boundaries().iter().map(Vec::len).sum()
+    }
+
+    /// The number of boundaries enclosing space that is not part of the region.
+    #[must_use]
+    pub fn holes(&self) -> usize {
+        self.boundaries()
+            .iter()
+            .filter(|corners| twice_signed_area(corners) < 0)
+            .count()
+    }
+
+    /// The top-left and bottom-right cells, inclusive, of the smallest box holding the region.
+    #[must_use]
+    pub fn bounding_box(&self) -> Option<(Position, Position)> {
+        self.cells.iter().fold(None, |bounds, cell| {
+            Some(
+                bounds.map_or((*cell, *cell), |(min, max): (Position, Position)| {
+                    (
+                        Position::new(min.row.min(cell.row), min.col.min(cell.col)),
+                        Position::new(max.row.max(cell.row), max.col.max(cell.col)),
+                    )
+                }),
+            )
+        })
+    }
+
+    /// Traces every boundary as the ordered corners of a polygon, each starting from its
+    /// top-left corner.
+    ///
+    /// Outer boundaries run clockwise and holes counter-clockwise. Cells touching only at a
+    /// corner are kept on separate boundaries.
+    #[must_use]
+    pub fn boundaries(&self) -> Vec<Vec<Position>> {
+        let mut edges = self.edges();
+        edges.sort_by_key(|(from, to, _direction)| (from.row, from.col, to.row, to.col));
+        let mut outgoing: HashMap<Position, Vec<Edge>> = HashMap::new();
+        for edge in &edges {
+            outgoing.entry(edge.0).or_default().push(*edge);
+        }
+        // Where two edges leave the same corner, turning right keeps to the same cell.
+        let successor = |(_from, to, direction): Edge| {
+            let choices = &outgoing[&to];
+            *choices
+                .iter()
+                .find(|next| next.2 == direction.turn_right())
+                .unwrap_or(&choices[0])
+        };
+
+        let mut walked: HashSet<Edge> = HashSet::new();
+        let mut boundaries = Vec::new();
+        for &start in &edges {
+            if walked.contains(&start) {
+                continue;
+            }
+            let mut corners = vec![start.0];
+            let mut edge = start;
+            loop {
+                walked.insert(edge);
+                let next = successor(edge);
+                if next == start {
+                    break;
+                }
+                if next.2 != edge.2 {
+                    corners.push(next.0);
+                }
+                edge = next;
+            }
+            boundaries.push(corners);
+        }
+        boundaries
+    }
+
+    fn edges(&self) -> Vec<Edge> {
+        let outside = |cell: &Position, side: Direction| {
+            cell.step(side)
+                .is_none_or(|neighbour| !self.cells.contains(&neighbour))
+        };
+        self.cells
+            .iter()
+            .flat_map(|cell| {
+                let Position { row, col } = *cell;
+                let top_left = *cell;
+                let top_right = Position::new(row, col + 1);
+                let bottom_right = Position::new(row + 1, col + 1);
+                let bottom_left = Position::new(row + 1, col);
+                [
+                    (Direction::Up, (top_left, top_right, Direction::Right)),
+                    (Direction::Right, (top_right, bottom_right, Direction::Down)),
+                    (
+                        Direction::Down,
+                        (bottom_right, bottom_left, Direction::Left),
+                    ),
+                    (Direction::Left, (bottom_left, top_left, Direction::Up)),
+                ]
+                .into_iter()
+                .filter(move |(side, _edge)| outside(cell, *side))
+                .map(|(_side, edge)| edge)
+            })
+            .collect()
+    }
+}
+
+/// Twice the area enclosed by `corners`, positive when they run clockwise down the page.
+fn twice_signed_area(corners: &[Position]) -> i128 {
+    let next = corners.iter().cycle().skip(1);
+    corners
+        .iter()
+        .zip(next)
+        .map(|(a, b)| i128::from(a.col) * i128::from(b.row) - i128::from(b.col) * i128::from(a.row))
+        .sum()
+}
+
+impl FromIterator<Position> for Region {
+    fn from_iter<I: IntoIterator<Item = Position>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl Components {
+    /// Iterates over every component as a [`Region`], in label order.
+    pub fn regions(&self) -> impl Iterator<Item = Region> + '_ {
+        self.iter().map(|cells| cells.iter().copied().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::Grid;
+
+    fn region(text: &str) -> Region {
+        Grid::<bool>::parse_glyphs(text)
+            .expect("Regions are drawn with # and .")
+            .to_indexed_iterator()
+            .filter(|(_pos, filled)| **filled)
+            .map(|(pos, _filled)| pos)
+            .collect()
+    }
+
+    #[test]
+    fn region_measurements() {
+        let ring = region("###\n#.#\n###");
+        assert_eq!(ring.area(), 8);
+        assert_eq!(ring.perimeter(), 16);
+        assert_eq!(ring.sides(), 8);
+        assert_eq!(ring.holes(), 1);
+        assert_eq!(
+            ring.bounding_box(),
+            Some((Position::new(0, 0), Position::new(2, 2)))
+        );
+        assert_eq!(Region::default().bounding_box(), None);
+
+        let ell = region(".#.\n.##");
+        assert_eq!((ell.area(), ell.perimeter(), ell.sides()), (3, 8, 6));
+        assert_eq!(ell.holes(), 0);
+    }
+
+    #[test]
+    fn region_boundaries() {
+        let ell = region("#.\n##");
+        assert_eq!(
+            ell.boundaries(),
+            vec![vec![
+                Position::new(0, 0),
+                Position::new(0, 1),
+                Position::new(1, 1),
+                Position::new(1, 2),
+                Position::new(2, 2),
+                Position::new(2, 0),
+            ]]
+        );
+        let ring = region("###\n#.#\n###");
+        assert_eq!(
+            ring.boundaries()[1],
+            vec![
+                Position::new(1, 1),
+                Position::new(2, 1),
+                Position::new(2, 2),
+                Position::new(1, 2),
+            ]
+        );
+        let pinched = region("#.\n.#");
+        assert_eq!(pinched.boundaries().len(), 2);
+        assert_eq!(pinched.sides(), 8);
+    }
+}
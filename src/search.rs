@@ -0,0 +1,348 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::grid::Grid;
+use crate::position::Position;
+
+/// The outcome of a shortest-path search: the distance to every state reached, and every way
+/// each of them was reached optimally.
+#[derive(Debug, Clone)]
+pub struct Paths<S> {
+    distances: HashMap<S, u64>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> Paths<S> {
+    #[must_use]
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    /// The distance to every state reached.
+    ///
+    /// When the search stopped at a goal, only states settled before it are guaranteed to be
+    /// at their shortest distance.
+    #[must_use]
+    pub const fn distances(&self) -> &HashMap<S, u64> {
+        &self.distances
+    }
+
+    /// The goals reached at the shortest distance, in the order they were found.
+    #[must_use]
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The distance to the nearest goal.
+    #[must_use]
+    pub fn goal_distance(&self) -> Option<u64> {
+        self.distance(self.goals.first()?)
+    }
+
+    /// One shortest path from a start to `target`, both included.
+    #[must_use]
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        self.distances.get(target)?;
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self
+            .predecessors
+            .get(path.last()?)
+            .and_then(|previous| previous.first())
+        {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// One shortest path from a start to the first goal, both included.
+    #[must_use]
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    /// Every state lying on some shortest path to `target`, including `target` itself.
+    #[must_use]
+    pub fn on_optimal_paths_to(&self, target: &S) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<S> = self
+            .distances
+            .contains_key(target)
+            .then(|| target.clone())
+            .into_iter()
+            .collect();
+        while let Some(state) = stack.pop() {
+            if let Some(previous) = self.predecessors.get(&state) {
+                stack.extend(previous.iter().filter(|p| !seen.contains(*p)).cloned());
+            }
+            seen.insert(state);
+        }
+        seen
+    }
+
+    /// Every state lying on some shortest path to any of the goals.
+    #[must_use]
+    pub fn on_optimal_paths(&self) -> HashSet<S> {
+        self.goals
+            .iter()
+            .flat_map(|goal| self.on_optimal_paths_to(goal))
+            .collect()
+    }
+}
+
+/// Searches outwards from `starts`, one unit step at a time, until every goal at the shortest
+/// distance has been reached.
+///
+/// Pass `|_| false` as `is_goal` to measure the distance to everything reachable.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    dijkstra(
+        starts,
+        |state| successors(state).into_iter().map(|next| (next, 1)),
+        is_goal,
+    )
+}
+
+/// Searches from `starts` through successors paired with the cost of moving to them, until
+/// every goal at the shortest distance has been reached.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// Like [`dijkstra`], but guided by a `heuristic` that never overestimates the remaining cost
+/// to a goal and never drops by more than the cost of a step.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut paths = Paths {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goals: Vec::new(),
+    };
+    // The heap refers to states by their index here, so states need not be ordered.
+    let mut states: Vec<S> = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            queue.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+        }
+    }
+
+    let mut settled: HashSet<S> = HashSet::new();
+    let mut goal_distance = None;
+    while let Some(Reverse((estimate, distance, index))) = queue.pop() {
+        if goal_distance.is_some_and(|best| estimate > best) {
+            break;
+        }
+        let state = states[index].clone();
+        if paths.distances.get(&state) != Some(&distance) || !settled.insert(state.clone()) {
+            continue;
+        }
+        if is_goal(&state) {
+            goal_distance = Some(distance);
+            paths.goals.push(state);
+            continue;
+        }
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            match paths.distances.get(&next) {
+                Some(&known) if known < next_distance => {}
+                // A step costing nothing can lead back to a settled state, whose predecessors
+                // are final: adding to them could make paths loop.
+                Some(_) if settled.contains(&next) => {}
+                Some(&known) if known == next_distance => {
+                    let previous = paths.predecessors.entry(next).or_default();
+                    if !previous.contains(&state) {
+                        previous.push(state.clone());
+                    }
+                }
+                _ => {
+                    paths.distances.insert(next.clone(), next_distance);
+                    paths.predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push(Reverse((
+                        next_distance + heuristic(&next),
+                        next_distance,
+                        states.len(),
+                    )));
+                    states.push(next);
+                }
+            }
+        }
+    }
+    paths
+}
+
+impl<T> Grid<T> {
+    /// Breadth-first search over orthogonal steps between cells for which `passable` holds.
+    pub fn bfs(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        passable: impl Fn(&T, &T) -> bool,
+        is_goal: impl FnMut(&Position) -> bool,
+    ) -> Paths<Position> {
+        bfs(
+            starts,
+            |position| self.passable_steps(position, &passable),
+            is_goal,
+        )
+    }
+
+    /// Dijkstra search over orthogonal steps, where `cost` prices each step between two cells
+    /// and returns `None` for impassable ones.
+    pub fn dijkstra(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        cost: impl Fn(&T, &T) -> Option<u64>,
+        is_goal: impl FnMut(&Position) -> bool,
+    ) -> Paths<Position> {
+        dijkstra(
+            starts,
+            |position| self.priced_steps(position, &cost),
+            is_goal,
+        )
+    }
+
+    /// A* search towards `goal` over orthogonal steps, guided by the Manhattan distance.
+    ///
+    /// Every step must cost at least 1 for the result to be a shortest path.
+    pub fn astar(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        cost: impl Fn(&T, &T) -> Option<u64>,
+        goal: Position,
+    ) -> Paths<Position> {
+        astar(
+            starts,
+            |position| self.priced_steps(position, &cost),
+            |position| (goal - *position).manhattan(),
+            |position| *position == goal,
+        )
+    }
+
+    fn passable_steps(
+        &self,
+        position: &Position,
+        passable: &impl Fn(&T, &T) -> bool,
+    ) -> Vec<Position> {
+        self.priced_steps(position, &|from, to| passable(from, to).then_some(1))
+            .into_iter()
+            .map(|(next, _cost)| next)
+            .collect()
+    }
+
+    fn priced_steps(
+        &self,
+        position: &Position,
+        cost: &impl Fn(&T, &T) -> Option<u64>,
+    ) -> Vec<(Position, u64)> {
+        let Some(from) = self.get(position) else {
+            return Vec::new();
+        };
+        self.orthogonal_neighbours(position)
+            .filter_map(|(_direction, next, to)| Some((next, cost(from, to)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E";
+
+    fn maze() -> Grid<char> {
+        Grid::parse_grid(MAZE, |c| c)
+    }
+
+    #[test]
+    fn grid_bfs() {
+        let grid = maze();
+        let paths = grid.bfs(
+            [Position::new(0, 0)],
+            |_, to| *to != '#',
+            |position| grid.get(position) == Some(&'E'),
+        );
+        assert_eq!(paths.goals(), [Position::new(2, 3)]);
+        assert_eq!(paths.goal_distance(), Some(5));
+        let path = paths.path().expect("The exit is reachable");
+        assert_eq!(path.len(), 6);
+        assert_eq!(path.first(), Some(&Position::new(0, 0)));
+        assert_eq!(paths.on_optimal_paths().len(), 10);
+
+        let everywhere = grid.bfs([Position::new(0, 0)], |_, to| *to != '#', |_| false);
+        assert_eq!(everywhere.distances().len(), 10);
+        assert_eq!(everywhere.distance(&Position::new(0, 2)), Some(2));
+        assert_eq!(everywhere.distance(&Position::new(0, 3)), None);
+    }
+
+    #[test]
+    fn grid_weighted_search() {
+        let grid = Grid::parse_grid("1191\n1911\n1111", |c| c.to_digit(10).map_or(0, u64::from));
+        let cost = |_: &u64, to: &u64| Some(*to);
+        let from = [Position::new(0, 0)];
+        let goal = Position::new(0, 3);
+        let dijkstra = grid.dijkstra(from, cost, |position| *position == goal);
+        let astar = grid.astar(from, cost, goal);
+        assert_eq!(dijkstra.goal_distance(), Some(7));
+        assert_eq!(astar.goal_distance(), Some(7));
+        assert_eq!(astar.path().map(|path| path.len()), Some(8));
+        assert_eq!(dijkstra.on_optimal_paths().len(), 9);
+        assert!(astar.distances().len() <= dijkstra.distances().len());
+    }
+
+    #[test]
+    fn grid_search_with_free_steps() {
+        let grid = Grid::parse_grid("aab", |c| c);
+        let paths = grid.dijkstra(
+            [Position::new(0, 0)],
+            |from, to| Some(u64::from(from != to)),
+            |_| false,
+        );
+        assert_eq!(paths.distance(&Position::new(0, 2)), Some(1));
+        assert_eq!(
+            paths.path_to(&Position::new(0, 2)),
+            Some(vec![
+                Position::new(0, 0),
+                Position::new(0, 1),
+                Position::new(0, 2)
+            ])
+        );
+        assert_eq!(paths.on_optimal_paths_to(&Position::new(0, 2)).len(), 3);
+    }
+
+    #[test]
+    fn generic_search_over_numbers() {
+        let paths = bfs([1u64], |n| [n + 1, n * 2], |n| *n == 10);
+        assert_eq!(paths.goal_distance(), Some(4));
+        assert_eq!(paths.path(), Some(vec![1, 2, 4, 5, 10]));
+        assert_eq!(paths.on_optimal_paths_to(&10).len(), 5);
+    }
+}
@@ -0,0 +1,165 @@
+use std::any::Any;
+use std::fmt::{self, Display};
+
+/// The answer to one part of a puzzle, whatever type the day computes it in.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        i128::try_from(value).map_or_else(|_| Self::Text(value.to_string()), Self::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// A day's puzzle: the input is parsed once and shared by both parts.
+pub trait Solution {
+    /// The day of December the puzzle was released on.
+    const DAY: u8;
+    type Input;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part_1(input: &Self::Input) -> Answer;
+    fn part_2(input: &Self::Input) -> Answer;
+}
+
+/// A [`Solution`] with its input type erased, so that every day can be listed together.
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    day: u8,
+    parse: fn(&str) -> Box<dyn Any>,
+    part_1: fn(&dyn Any) -> Answer,
+    part_2: fn(&dyn Any) -> Answer,
+}
+
+impl Solver {
+    #[must_use]
+    pub const fn of<S: Solution>() -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            day: S::DAY,
+            parse: |input| Box::new(S::parse(input)),
+            part_1: |input| S::part_1(downcast::<S>(input)),
+            part_2: |input| S::part_2(downcast::<S>(input)),
+        }
+    }
+
+    #[must_use]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    #[must_use]
+    pub fn parse(&self, input: &str) -> Parsed {
+        Parsed {
+            solver: *self,
+            input: (self.parse)(input),
+        }
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("Inputs are only handed back to the solver that parsed them")
+}
+
+/// An input parsed by a [`Solver`], ready for either part.
+#[derive(Debug)]
+pub struct Parsed {
+    solver: Solver,
+    input: Box<dyn Any>,
+}
+
+impl Parsed {
+    #[must_use]
+    pub fn part_1(&self) -> Answer {
+        (self.solver.part_1)(self.input.as_ref())
+    }
+
+    #[must_use]
+    pub fn part_2(&self) -> Answer {
+        (self.solver.part_2)(self.input.as_ref())
+    }
+}
+
+/// Declares each day's module and lists its [`Solution`] in `DAYS`, as `dayNN::DayNN` entries
+/// in order of day.
+#[macro_export]
+macro_rules! register_days {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every solved day, in order.
+        pub const DAYS: &[$crate::solution::Solver] =
+            &[$($crate::solution::Solver::of::<$module::$solution>()),*];
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        const DAY: u8 = 1;
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> Self::Input {
+            input.lines().map(str::to_owned).collect()
+        }
+
+        fn part_1(input: &Self::Input) -> Answer {
+            input.len().into()
+        }
+
+        fn part_2(input: &Self::Input) -> Answer {
+            input.concat().into()
+        }
+    }
+
+    #[test]
+    fn solver_shares_one_parse() {
+        let solver = Solver::of::<Lengths>();
+        assert_eq!(solver.day(), 1);
+        let parsed = solver.parse("ab\ncd");
+        assert_eq!(parsed.part_1(), Answer::Number(2));
+        assert_eq!(parsed.part_2().to_string(), "abcd");
+        assert_eq!(Answer::from(-3_i64).to_string(), "-3");
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+use crate::glyph::{write_glyph_rows, Glyph};
+use crate::grid::Grid;
+use crate::offset::Offset;
+use crate::position::Position;
+
+/// Cells on an unbounded plane, stored only where something is present.
+///
+/// Cells are keyed by signed [`Offset`]s from an arbitrary origin, so coordinates may go
+/// negative. Iteration is in reading order: top to bottom, then left to right.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SparseGrid<T> {
+    cells: BTreeMap<Offset, T>,
+    bounds: Option<(Offset, Offset)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            cells: BTreeMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Parses text one row per line; characters `str_caster` maps to `None` are left empty.
+    pub fn parse<U>(value: &str, str_caster: U) -> Self
+    where
+        U: Fn(char) -> Option<T>,
+    {
+        let str_caster = &str_caster;
+        value
+            .lines()
+            .zip(0..)
+            .flat_map(|(line, row)| {
+                line.chars()
+                    .zip(0..)
+                    .filter_map(move |(c, col)| Some((Offset::new(row, col), str_caster(c)?)))
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    #[must_use]
+    pub fn get(&self, at: &Offset) -> Option<&T> {
+        self.cells.get(at)
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, at: &Offset) -> Option<&mut T> {
+        self.cells.get_mut(at)
+    }
+
+    #[must_use]
+    pub fn contains(&self, at: &Offset) -> bool {
+        self.cells.contains_key(at)
+    }
+
+    /// Stores `value` at `at`, returning the value previously there.
+    pub fn insert(&mut self, at: Offset, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (at, at),
+            Some((min, max)) => (
+                Offset::new(min.row.min(at.row), min.col.min(at.col)),
+                Offset::new(max.row.max(at.row), max.col.max(at.col)),
+            ),
+        });
+        self.cells.insert(at, value)
+    }
+
+    /// Empties the cell at `at`, returning its value.
+    pub fn remove(&mut self, at: &Offset) -> Option<T> {
+        let removed = self.cells.remove(at)?;
+        if let Some((min, max)) = self.bounds {
+            if at.row == min.row || at.row == max.row || at.col == min.col || at.col == max.col {
+                self.bounds = self.cells.keys().fold(None, |bounds, at| {
+                    Some(bounds.map_or((*at, *at), |(min, max): (Offset, Offset)| {
+                        (
+                            Offset::new(min.row.min(at.row), min.col.min(at.col)),
+                            Offset::new(max.row.max(at.row), max.col.max(at.col)),
+                        )
+                    }))
+                });
+            }
+        }
+        Some(removed)
+    }
+
+    /// The top-left and bottom-right corners, inclusive, of the smallest box holding every cell.
+    #[must_use]
+    pub const fn bounds(&self) -> Option<(Offset, Offset)> {
+        self.bounds
+    }
+
+    /// Iterates over the occupied cells in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Offset, &T)> {
+        self.cells.iter().map(|(at, value)| (*at, value))
+    }
+
+    /// Keeps the cells of `grid` accepted by `keep`, at the offsets matching their positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a grid position does not fit in a signed offset.
+    pub fn from_grid(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        grid.to_indexed_iterator()
+            .filter(|(_pos, value)| keep(value))
+            .map(|(pos, value)| {
+                (
+                    Offset::try_from(pos).expect("AOC grids are small"),
+                    value.clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// Densifies the area of `shape` whose top-left corner is `origin`, filling empty cells.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shape` does not fit in signed offsets.
+    #[must_use]
+    pub fn to_grid_within(&self, origin: Offset, shape: &Position, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let rows = i64::try_from(shape.row).expect("AOC grids are small");
+        let cols = i64::try_from(shape.col).expect("AOC grids are small");
+        Grid::new(
+            (0..rows)
+                .map(|row| {
+                    (0..cols)
+                        .map(|col| {
+                            self.get(&(origin + Offset::new(row, col)))
+                                .unwrap_or(&fill)
+                                .clone()
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    /// Densifies the bounding box, returning the offset of its top-left corner with the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounding box is too large to address with positions.
+    #[must_use]
+    pub fn to_grid(&self, fill: T) -> Option<(Offset, Grid<T>)>
+    where
+        T: Clone,
+    {
+        let (min, max) = self.bounds?;
+        let shape = Position::try_from(max - min + Offset::new(1, 1))
+            .expect("The bounding box has a positive size");
+        Some((min, self.to_grid_within(min, &shape, fill)))
+    }
+}
+
+impl<T> FromIterator<(Offset, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Offset, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Offset, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Offset, T)>>(&mut self, iter: I) {
+        for (at, value) in iter {
+            self.insert(at, value);
+        }
+    }
+}
+
+/// Renders the bounding box with [`Glyph::to_glyph`], drawing empty cells as `.`.
+impl<T: Glyph> Display for SparseGrid<T> {
+    #[allow(clippy::cast_possible_wrap)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        let height = usize::try_from(max.row - min.row + 1).map_err(|_| fmt::Error)?;
+        let width = usize::try_from(max.col - min.col + 1).map_err(|_| fmt::Error)?;
+        write_glyph_rows(f, height, width, |row, col| {
+            let at = min + Offset::new(row as i64, col as i64);
+            self.get(&at).map_or('.', Glyph::to_glyph)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sparse_grid_bounds_and_order() {
+        let mut sparse: SparseGrid<char> = [
+            (Offset::new(2, -1), 'c'),
+            (Offset::new(-3, 4), 'a'),
+            (Offset::new(0, 0), 'b'),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            sparse.bounds(),
+            Some((Offset::new(-3, -1), Offset::new(2, 4)))
+        );
+        assert_eq!(sparse.iter().map(|(_, c)| *c).collect::<String>(), "abc");
+        assert_eq!(sparse.remove(&Offset::new(-3, 4)), Some('a'));
+        assert_eq!(sparse.remove(&Offset::new(-3, 4)), None);
+        assert_eq!(
+            sparse.bounds(),
+            Some((Offset::new(0, -1), Offset::new(2, 0)))
+        );
+        assert_eq!(sparse.to_string(), ".b\n..\nc.");
+    }
+
+    #[test]
+    fn sparse_grid_dense_conversion() {
+        let dense = Grid::parse_grid("..#\n#..", |c| c);
+        let sparse = SparseGrid::from_grid(&dense, |c| *c == '#');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(
+            sparse,
+            SparseGrid::parse("..#\n#..", |c| (c == '#').then_some(c))
+        );
+        assert_eq!(sparse.to_grid('.'), Some((Offset::ZERO, dense)));
+        assert_eq!(
+            sparse.to_grid_within(Offset::new(-1, 1), &Position::new(2, 3), '.'),
+            Grid::parse_grid("...\n.#.", |c| c)
+        );
+        assert_eq!(SparseGrid::<char>::new().to_grid('.'), None);
+    }
+}
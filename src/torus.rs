@@ -0,0 +1,218 @@
+use crate::grid::Grid;
+use crate::offset::Offset;
+use crate::position::Position;
+
+/// A rectangular area whose opposite edges are glued together, so any move wraps back inside.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Torus {
+    shape: Position,
+}
+
+impl Torus {
+    /// A torus of `shape.row` rows and `shape.col` columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension is zero or does not fit in a signed offset.
+    #[must_use]
+    pub fn new(shape: Position) -> Self {
+        assert!(
+            shape.row > 0 && shape.col > 0,
+            "A torus needs at least one cell"
+        );
+        assert!(
+            Offset::try_from(shape).is_ok(),
+            "Torus dimensions must fit in signed offsets"
+        );
+        Self { shape }
+    }
+
+    #[must_use]
+    pub const fn shape(&self) -> Position {
+        self.shape
+    }
+
+    /// Maps any signed offset from the origin onto the cell it wraps around to.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub const fn wrap(&self, at: Offset) -> Position {
+        // Both dimensions were checked to fit in an i64, and rem_euclid is never negative.
+        Position::new(
+            at.row.rem_euclid(self.shape.row as i64) as u64,
+            at.col.rem_euclid(self.shape.col as i64) as u64,
+        )
+    }
+
+    /// Moves `position` by `offset`, wrapping around the edges as many times as needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` does not fit in a signed offset.
+    #[must_use]
+    pub fn add(&self, position: Position, offset: Offset) -> Position {
+        self.wrap(Offset::try_from(position).expect("Positions on a torus are small") + offset)
+    }
+
+    /// Splits the area into `tile_rows` × `tile_cols` tiles.
+    ///
+    /// With `exclude_middle`, the single lines between neighbouring tiles belong to no tile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more tiles, or separator lines, than rows or columns, or if with
+    /// `exclude_middle` the lines left between the separators cannot be shared out evenly.
+    #[must_use]
+    pub fn tiling(&self, tile_rows: u64, tile_cols: u64, exclude_middle: bool) -> Tiling {
+        Tiling {
+            rows: Band::new(self.shape.row, tile_rows, exclude_middle),
+            cols: Band::new(self.shape.col, tile_cols, exclude_middle),
+        }
+    }
+
+    /// The four quadrants around the middle row and column, which belong to none of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension is even, as there is no single middle line then.
+    #[must_use]
+    pub fn quadrants(&self) -> Tiling {
+        self.tiling(2, 2, true)
+    }
+}
+
+/// How one axis of a [`Tiling`] is cut into consecutive bands.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Band {
+    size: u64,
+    parts: u64,
+    exclude_middle: bool,
+}
+
+impl Band {
+    fn new(size: u64, parts: u64, exclude_middle: bool) -> Self {
+        let separators = if exclude_middle {
+            parts.saturating_sub(1)
+        } else {
+            0
+        };
+        assert!(
+            parts > 0 && parts + separators <= size,
+            "Cannot cut {size} cells into {parts} tiles"
+        );
+        assert!(
+            !exclude_middle || (size - separators).is_multiple_of(parts),
+            "Cannot cut {size} cells into {parts} equal tiles around separators"
+        );
+        Self {
+            size,
+            parts,
+            exclude_middle,
+        }
+    }
+
+    fn part_of(self, coordinate: u64) -> Option<u64> {
+        if coordinate >= self.size {
+            return None;
+        }
+        if !self.exclude_middle {
+            return Some(coordinate * self.parts / self.size);
+        }
+        let width = (self.size - (self.parts - 1)) / self.parts;
+        (coordinate % (width + 1) != width).then_some(coordinate / (width + 1))
+    }
+}
+
+/// A partition of a [`Torus`] into a grid of tiles.
+///
+/// Without separator lines tiles along an axis differ in size by at most one. With them, tiles
+/// along an axis are all the same size and only the separator lines belong to no tile.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Tiling {
+    rows: Band,
+    cols: Band,
+}
+
+impl Tiling {
+    /// The row and column of the tile holding `position`, if any.
+    #[must_use]
+    pub fn tile_of(&self, position: &Position) -> Option<Position> {
+        Some(Position::new(
+            self.rows.part_of(position.row)?,
+            self.cols.part_of(position.col)?,
+        ))
+    }
+
+    /// Counts how many of `positions` fall in each tile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of tiles does not fit in memory.
+    #[must_use]
+    pub fn count(&self, positions: impl IntoIterator<Item = Position>) -> Grid<u64> {
+        let rows = usize::try_from(self.rows.parts).expect("Tilings are small");
+        let cols = usize::try_from(self.cols.parts).expect("Tilings are small");
+        let mut counts = Grid::filled(rows, cols, 0);
+        for tile in positions.into_iter().filter_map(|pos| self.tile_of(&pos)) {
+            *counts.get_mut(&tile).expect("Tiles lie inside the tiling") += 1;
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn torus_wrapping() {
+        let torus = Torus::new(Position::new(7, 11));
+        assert_eq!(torus.wrap(Offset::new(-1, 11)), Position::new(6, 0));
+        assert_eq!(
+            torus.add(Position::new(4, 2), Offset::new(-3, 2) * 5),
+            Position::new(3, 1)
+        );
+    }
+
+    #[test]
+    fn torus_tiling() {
+        let torus = Torus::new(Position::new(7, 11));
+        let quadrants = torus.quadrants();
+        assert_eq!(
+            quadrants.tile_of(&Position::new(2, 4)),
+            Some(Position::new(0, 0))
+        );
+        assert_eq!(quadrants.tile_of(&Position::new(3, 4)), None);
+        assert_eq!(quadrants.tile_of(&Position::new(4, 5)), None);
+        assert_eq!(
+            quadrants.tile_of(&Position::new(6, 10)),
+            Some(Position::new(1, 1))
+        );
+        let everywhere: Vec<Position> = (0..7)
+            .flat_map(|row| (0..11).map(move |col| Position::new(row, col)))
+            .collect();
+        assert_eq!(
+            quadrants.count(everywhere.iter().copied()),
+            Grid::filled(2, 2, 15)
+        );
+        for position in &everywhere {
+            let mirrored = Position::new(6 - position.row, 10 - position.col);
+            assert_eq!(
+                quadrants.tile_of(&mirrored),
+                quadrants
+                    .tile_of(position)
+                    .map(|tile| Position::new(1 - tile.row, 1 - tile.col))
+            );
+        }
+        let thirds = torus.tiling(1, 3, false);
+        assert_eq!(
+            thirds.count((0..11).map(|col| Position::new(0, col))),
+            Grid::new(vec![vec![4, 4, 3]])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "equal tiles")]
+    fn torus_quadrants_need_a_middle_line() {
+        let _ = Torus::new(Position::new(6, 7)).quadrants();
+    }
+}
@@ -0,0 +1,221 @@
+use crate::grid::Grid;
+use crate::position::Position;
+
+/// One of the eight ways to rotate or reflect a rectangle onto itself.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Symmetry {
+    Identity,
+    /// A quarter turn clockwise.
+    Rotate90,
+    Rotate180,
+    /// A quarter turn counter-clockwise.
+    Rotate270,
+    /// Mirrors left and right.
+    FlipHorizontal,
+    /// Mirrors top and bottom.
+    FlipVertical,
+    /// Mirrors along the main diagonal, swapping rows and columns.
+    Transpose,
+    /// Mirrors along the anti-diagonal.
+    AntiTranspose,
+}
+
+impl Symmetry {
+    pub const ALL: [Self; 8] = [
+        Self::Identity,
+        Self::Rotate90,
+        Self::Rotate180,
+        Self::Rotate270,
+        Self::FlipHorizontal,
+        Self::FlipVertical,
+        Self::Transpose,
+        Self::AntiTranspose,
+    ];
+
+    /// Returns `true` if this symmetry swaps the number of rows and columns.
+    #[must_use]
+    pub const fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Self::Rotate90 | Self::Rotate270 | Self::Transpose | Self::AntiTranspose
+        )
+    }
+}
+
+/// A read-only view of a [`Grid<T>`] under a [`Symmetry`], mapping coordinates lazily.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    symmetry: Symmetry,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    #[must_use]
+    pub const fn symmetry(&self) -> Symmetry {
+        self.symmetry
+    }
+
+    /// The shape of the transformed grid, as a number of rows and columns.
+    #[must_use]
+    pub const fn shape(&self) -> Position {
+        let Position { row, col } = self.grid.shape();
+        if self.symmetry.swaps_axes() {
+            Position::new(col, row)
+        } else {
+            Position::new(row, col)
+        }
+    }
+
+    /// The position in the underlying grid shown at `position` of this view.
+    #[must_use]
+    pub const fn source_position(&self, position: &Position) -> Option<Position> {
+        if !position.is_within(&self.shape()) {
+            return None;
+        }
+        let Position {
+            row: height,
+            col: width,
+        } = self.grid.shape();
+        let Position { row, col } = *position;
+        Some(match self.symmetry {
+            Symmetry::Identity => Position::new(row, col),
+            Symmetry::Rotate90 => Position::new(height - 1 - col, row),
+            Symmetry::Rotate180 => Position::new(height - 1 - row, width - 1 - col),
+            Symmetry::Rotate270 => Position::new(col, width - 1 - row),
+            Symmetry::FlipHorizontal => Position::new(row, width - 1 - col),
+            Symmetry::FlipVertical => Position::new(height - 1 - row, col),
+            Symmetry::Transpose => Position::new(col, row),
+            Symmetry::AntiTranspose => Position::new(height - 1 - col, width - 1 - row),
+        })
+    }
+
+    #[must_use]
+    pub fn get(&self, position: &Position) -> Option<&'a T> {
+        self.grid.get(&self.source_position(position)?)
+    }
+
+    /// Iterates over the cells of the view in its own reading order.
+    pub fn to_indexed_iterator(&self) -> impl Iterator<Item = (Position, &'a T)> + 'a {
+        let view = *self;
+        let Position {
+            row: rows,
+            col: cols,
+        } = self.shape();
+        (0..rows).flat_map(move |row| {
+            (0..cols).filter_map(move |col| {
+                let position = Position::new(row, col);
+                view.get(&position).map(|value| (position, value))
+            })
+        })
+    }
+
+    /// Iterates over the rows of the view, each as an iterator over its cells.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> + 'a {
+        let view = *self;
+        let Position {
+            row: rows,
+            col: cols,
+        } = self.shape();
+        (0..rows)
+            .map(move |row| (0..cols).filter_map(move |col| view.get(&Position::new(row, col))))
+    }
+
+    /// Copies the view into a new grid.
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(self.rows().map(|row| row.cloned().collect()).collect())
+    }
+}
+
+impl<T> Grid<T> {
+    #[must_use]
+    pub const fn view(&self, symmetry: Symmetry) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            symmetry,
+        }
+    }
+
+    #[must_use]
+    pub const fn transpose(&self) -> GridView<'_, T> {
+        self.view(Symmetry::Transpose)
+    }
+
+    #[must_use]
+    pub const fn rotate_90(&self) -> GridView<'_, T> {
+        self.view(Symmetry::Rotate90)
+    }
+
+    #[must_use]
+    pub const fn rotate_180(&self) -> GridView<'_, T> {
+        self.view(Symmetry::Rotate180)
+    }
+
+    #[must_use]
+    pub const fn rotate_270(&self) -> GridView<'_, T> {
+        self.view(Symmetry::Rotate270)
+    }
+
+    #[must_use]
+    pub const fn flip_horizontal(&self) -> GridView<'_, T> {
+        self.view(Symmetry::FlipHorizontal)
+    }
+
+    #[must_use]
+    pub const fn flip_vertical(&self) -> GridView<'_, T> {
+        self.view(Symmetry::FlipVertical)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render(view: &GridView<'_, char>) -> String {
+        view.rows()
+            .map(Iterator::collect::<String>)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn grid_view_symmetries() {
+        let grid = Grid::parse_grid("abc\ndef", |c| c);
+        assert_eq!(render(&grid.view(Symmetry::Identity)), "abc\ndef");
+        assert_eq!(render(&grid.rotate_90()), "da\neb\nfc");
+        assert_eq!(render(&grid.rotate_180()), "fed\ncba");
+        assert_eq!(render(&grid.rotate_270()), "cf\nbe\nad");
+        assert_eq!(render(&grid.flip_horizontal()), "cba\nfed");
+        assert_eq!(render(&grid.flip_vertical()), "def\nabc");
+        assert_eq!(render(&grid.transpose()), "ad\nbe\ncf");
+        assert_eq!(render(&grid.view(Symmetry::AntiTranspose)), "fc\neb\nda");
+    }
+
+    #[test]
+    fn grid_view_access() {
+        let grid = Grid::parse_grid("abc\ndef", |c| c);
+        let rotated = grid.rotate_90();
+        assert_eq!(rotated.shape(), Position::new(3, 2));
+        assert_eq!(rotated.get(&Position::new(0, 1)), Some(&'a'));
+        assert_eq!(rotated.get(&Position::new(0, 2)), None);
+        assert_eq!(
+            rotated.to_indexed_iterator().last(),
+            Some((Position::new(2, 1), &'c'))
+        );
+        assert_eq!(rotated.to_grid(), Grid::parse_grid("da\neb\nfc", |c| c));
+        for symmetry in Symmetry::ALL {
+            assert_eq!(grid.view(symmetry).to_indexed_iterator().count(), 6);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use rayon::prelude::*;
+
+use crate::grid::Grid;
+use crate::position::Position;
+
+/// A rectangular sub-grid of a [`Grid<T>`], addressed with coordinates local to its top-left
+/// corner.
+#[derive(Debug)]
+pub struct Window<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Position,
+    shape: Position,
+}
+
+impl<T> Clone for Window<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Window<'_, T> {}
+
+impl<'a, T> Window<'a, T> {
+    /// The position of the top-left corner of this window in the underlying grid.
+    #[must_use]
+    pub const fn origin(&self) -> Position {
+        self.origin
+    }
+
+    #[must_use]
+    pub const fn shape(&self) -> Position {
+        self.shape
+    }
+
+    /// The value at `local`, counted from the top-left corner of this window.
+    #[must_use]
+    pub fn get(&self, local: &Position) -> Option<&'a T> {
+        if !local.is_within(&self.shape) {
+            return None;
+        }
+        self.grid.get(&Position::new(
+            self.origin.row + local.row,
+            self.origin.col + local.col,
+        ))
+    }
+
+    /// Iterates over the cells of this window in reading order, with local positions.
+    pub fn to_indexed_iterator(&self) -> impl Iterator<Item = (Position, &'a T)> + 'a {
+        let window = *self;
+        (0..self.shape.row).flat_map(move |row| {
+            (0..window.shape.col).filter_map(move |col| {
+                let local = Position::new(row, col);
+                window.get(&local).map(|value| (local, value))
+            })
+        })
+    }
+}
+
+/// A window equals a grid of the same shape holding the same values.
+impl<T: PartialEq> PartialEq<Grid<T>> for Window<'_, T> {
+    fn eq(&self, pattern: &Grid<T>) -> bool {
+        self.shape == pattern.shape()
+            && self
+                .to_indexed_iterator()
+                .all(|(local, value)| pattern.get(&local) == Some(value))
+    }
+}
+
+impl<T> Grid<T> {
+    /// How many rows and columns of origins a `height` × `width` window can take.
+    const fn window_origins(&self, height: usize, width: usize) -> (usize, usize) {
+        (
+            (self.height() + 1).saturating_sub(height),
+            (self.width() + 1).saturating_sub(width),
+        )
+    }
+
+    const fn window_at(
+        &self,
+        row: usize,
+        col: usize,
+        height: usize,
+        width: usize,
+    ) -> Window<'_, T> {
+        Window {
+            grid: self,
+            origin: Position::new(row as u64, col as u64),
+            shape: Position::new(height as u64, width as u64),
+        }
+    }
+
+    /// Iterates over every `height` × `width` sub-grid, in reading order of their origins.
+    pub fn windows(&self, height: usize, width: usize) -> impl Iterator<Item = Window<'_, T>> {
+        let (rows, cols) = self.window_origins(height, width);
+        (0..rows)
+            .flat_map(move |row| (0..cols).map(move |col| self.window_at(row, col, height, width)))
+    }
+
+    /// Like [`Grid::windows`], but spread over the rayon thread pool.
+    #[must_use]
+    pub fn par_windows(
+        &self,
+        height: usize,
+        width: usize,
+    ) -> impl IndexedParallelIterator<Item = Window<'_, T>>
+    where
+        T: Sync,
+    {
+        let (rows, cols) = self.window_origins(height, width);
+        (0..rows * cols)
+            .into_par_iter()
+            .map(move |index| self.window_at(index / cols, index % cols, height, width))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn grid_windows() {
+        let grid = Grid::parse_grid("abcd\nefgh\nijkl", |c| c);
+        let windows: Vec<_> = grid.windows(2, 3).collect();
+        assert_eq!(
+            windows.iter().map(Window::origin).collect::<Vec<_>>(),
+            vec![
+                Position::new(0, 0),
+                Position::new(0, 1),
+                Position::new(1, 0),
+                Position::new(1, 1)
+            ]
+        );
+        assert_eq!(windows[3].get(&Position::new(1, 2)), Some(&'l'));
+        assert_eq!(windows[3].get(&Position::new(2, 0)), None);
+        assert!(windows[1] == Grid::parse_grid("bcd\nfgh", |c| c));
+        assert!(windows[1] != Grid::parse_grid("bc\nfg", |c| c));
+        assert_eq!(grid.windows(4, 1).count(), 0);
+        assert_eq!(grid.windows(3, 4).count(), 1);
+    }
+
+    #[test]
+    fn grid_par_windows() {
+        let grid = Grid::parse_grid("abcd\nefgh\nijkl", |c| c);
+        let origins: Vec<_> = grid.par_windows(2, 2).map(|w| w.origin()).collect();
+        assert_eq!(
+            origins,
+            grid.windows(2, 2).map(|w| w.origin()).collect::<Vec<_>>()
+        );
+    }
+}